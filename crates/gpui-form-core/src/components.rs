@@ -7,6 +7,18 @@ fn default_true() -> bool {
     true
 }
 
+fn default_textarea_rows() -> usize {
+    4
+}
+
+fn default_textarea_max_rows() -> usize {
+    10
+}
+
+fn default_otp_length() -> usize {
+    6
+}
+
 pub trait ComponentOption {}
 
 pub trait ComponentDefinition {
@@ -17,6 +29,10 @@ pub struct FieldInformation<T: ComponentOption> {
     pub options: T,
     pub name: String,
     pub r#type: syn::Ident,
+    /// Placeholder of the field's `InputState`, for the components built on one.
+    pub placeholder: Option<String>,
+    /// Whether the field is an `Option<T>`, `r#type` being `T`.
+    pub optional: bool,
 }
 
 impl<T: ComponentOption> FieldInformation<T> {
@@ -25,8 +41,20 @@ impl<T: ComponentOption> FieldInformation<T> {
             options,
             name,
             r#type,
+            placeholder: None,
+            optional: false,
         }
     }
+
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: Option<String>) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
 }
 
 #[derive(Clone, ComponentOption, Debug, Default, Eq, FromMeta, PartialEq)]
@@ -37,6 +65,121 @@ pub struct BehaviourDropdownOptions {
     pub searchable: bool,
 }
 
+#[derive(Clone, ComponentOption, Debug, Default, Eq, FromMeta, PartialEq)]
+pub struct BehaviourInputOptions {
+    #[darling(default)]
+    pub masked: bool,
+}
+
+#[derive(Clone, ComponentOption, Debug, Eq, FromMeta, PartialEq)]
+pub struct BehaviourTextareaOptions {
+    #[darling(default = "default_textarea_rows")]
+    pub rows: usize,
+    #[darling(default)]
+    pub auto_grow: bool,
+}
+
+impl Default for BehaviourTextareaOptions {
+    fn default() -> Self {
+        Self {
+            rows: default_textarea_rows(),
+            auto_grow: false,
+        }
+    }
+}
+
+#[derive(Clone, ComponentOption, Debug, Eq, FromMeta, PartialEq)]
+pub struct BehaviourOtpOptions {
+    #[darling(default = "default_otp_length")]
+    pub length: usize,
+    #[darling(default)]
+    pub masked: bool,
+}
+
+impl Default for BehaviourOtpOptions {
+    fn default() -> Self {
+        Self {
+            length: default_otp_length(),
+            masked: false,
+        }
+    }
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, FromMeta, PartialEq)]
+pub struct BehaviourTimePickerOptions {
+    #[darling(default)]
+    pub seconds: bool,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, FromMeta, PartialEq)]
+pub struct BehaviourDatetimePickerOptions {
+    #[darling(default)]
+    pub seconds: bool,
+    /// Whether the field's type carries a time zone, as `chrono::DateTime<Tz>` and
+    /// `jiff::Zoned` do. Told by the derive from the type rather than by the attribute.
+    #[darling(skip)]
+    pub zoned: bool,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, PartialEq)]
+pub struct BehaviourDateRangePickerOptions {
+    pub presets: bool,
+}
+
+/// Row component of a `list(item = ...)` field.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+pub enum ListItem {
+    #[default]
+    Input,
+    NumberInput,
+    Nested,
+}
+
+impl FromMeta for ListItem {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        value
+            .parse()
+            .map_err(|_| darling::Error::unknown_value(value))
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Path(path) => match path.path.get_ident() {
+                Some(ident) => Self::from_string(&ident.to_string()),
+                None => Err(darling::Error::unexpected_expr_type(expr)),
+            },
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, FromMeta, PartialEq)]
+pub struct BehaviourListOptions {
+    #[darling(default)]
+    pub item: ListItem,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, PartialEq)]
+pub struct BehaviourSliderOptions {
+    pub min: Option<&'static str>,
+    pub max: Option<&'static str>,
+    pub step: Option<&'static str>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Orientation {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, Eq, PartialEq)]
+pub struct BehaviourRadioGroupOptions {
+    pub orientation: Orientation,
+}
+
 #[derive(Clone, ComponentOption, Debug, Eq, FromMeta, PartialEq)]
 pub struct BehaviourCustomOptions {
     #[darling(default = "default_true", rename = "uw")]
@@ -53,6 +196,7 @@ pub struct CustomOptions {
 }
 
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
+#[darling(and_then = Self::validate)]
 pub struct DropdownOptions {
     #[darling(flatten)]
     pub behaviour: BehaviourDropdownOptions,
@@ -63,23 +207,114 @@ pub struct DropdownOptions {
 }
 
 impl DropdownOptions {
-    pub fn named_index(&self) -> Option<&syn::Path> {
-        if self.named_index.is_some() && self.index_default {
-            panic!("Cannot specify both named_index and index_default");
+    fn validate(self) -> darling::Result<Self> {
+        if let Some(named_index) = &self.named_index
+            && self.index_default
+        {
+            return Err(
+                darling::Error::custom("Cannot specify both index and default")
+                    .with_span(named_index),
+            );
         }
+        Ok(self)
+    }
+
+    pub fn named_index(&self) -> Option<&syn::Path> {
         self.named_index.as_ref()
     }
 
     pub fn index_default(&self) -> bool {
-        if self.named_index.is_some() && self.index_default {
-            panic!("Cannot specify both named_index and index_default");
-        }
         self.index_default
     }
 }
 
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
-pub struct InputOptions;
+#[darling(and_then = Self::validate)]
+pub struct RadioGroupOptions {
+    #[darling(default)]
+    horizontal: bool,
+    #[darling(default)]
+    vertical: bool,
+}
+
+impl RadioGroupOptions {
+    fn validate(self) -> darling::Result<Self> {
+        if self.horizontal && self.vertical {
+            return Err(darling::Error::custom(
+                "Cannot specify both horizontal and vertical",
+            ));
+        }
+        Ok(self)
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        if self.horizontal {
+            Orientation::Horizontal
+        } else {
+            Orientation::Vertical
+        }
+    }
+}
+
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct TextareaOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourTextareaOptions,
+    #[darling(default = "default_textarea_max_rows")]
+    pub max_rows: usize,
+}
+
+impl Default for TextareaOptions {
+    fn default() -> Self {
+        Self {
+            behaviour: BehaviourTextareaOptions::default(),
+            max_rows: default_textarea_max_rows(),
+        }
+    }
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct OtpOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourOtpOptions,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct InputOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourInputOptions,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct DateRangePickerOptions {
+    #[darling(default)]
+    pub presets: Option<syn::Path>,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct TimePickerOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourTimePickerOptions,
+}
+
+#[derive(Clone, ComponentOption, Debug, Default, FromMeta)]
+#[darling(from_word = || Ok(Self::default()))]
+pub struct DatetimePickerOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourDatetimePickerOptions,
+}
+
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct ListOptions {
+    #[darling(flatten)]
+    pub behaviour: BehaviourListOptions,
+}
+
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
 pub struct NumberInputOptions;
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
@@ -87,7 +322,56 @@ pub struct CheckboxOptions;
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
 pub struct SwitchOptions;
 #[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct MultiSelectOptions;
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct NestedOptions;
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
 pub struct DatePickerOptions;
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct ColorPickerOptions;
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct InferredOptions;
+
+#[derive(Clone, ComponentOption, Debug, FromMeta)]
+pub struct SliderOptions {
+    #[darling(default)]
+    pub min: Option<Box<syn::Expr>>,
+    #[darling(default)]
+    pub max: Option<Box<syn::Expr>>,
+    #[darling(default)]
+    pub step: Option<Box<syn::Expr>>,
+}
+
+/// Rules of `#[gpui_form(validate(...))]`, checked by the generated `XFormValueHolder::validate`.
+#[derive(Clone, Debug, Default, FromMeta)]
+#[darling(and_then = Self::validate)]
+pub struct ValidationRules {
+    #[darling(default)]
+    pub required: bool,
+    #[darling(default)]
+    pub min_len: Option<usize>,
+    #[darling(default)]
+    pub max_len: Option<usize>,
+    #[darling(default)]
+    pub min: Option<syn::Expr>,
+    #[darling(default)]
+    pub max: Option<syn::Expr>,
+    #[darling(default)]
+    pub pattern: Option<syn::LitStr>,
+}
+
+impl ValidationRules {
+    fn validate(self) -> darling::Result<Self> {
+        if let Some(pattern) = &self.pattern
+            && let Err(error) = regex::Regex::new(&pattern.value())
+        {
+            return Err(
+                darling::Error::custom(format!("invalid `pattern`: {error}")).with_span(pattern),
+            );
+        }
+        Ok(self)
+    }
+}
 
 #[derive(Clone, ComponentDefinitions, Debug, EnumDiscriminants, FromMeta)]
 #[strum_discriminants(derive(EnumString, Display, IntoStaticStr))]
@@ -95,42 +379,133 @@ pub struct DatePickerOptions;
 #[strum_discriminants(strum(serialize_all = "snake_case"))]
 #[darling(rename_all = "snake_case")]
 pub enum Components {
-    Input,
+    Input(InputOptions),
+    Textarea(TextareaOptions),
+    Otp(OtpOptions),
     NumberInput,
     Checkbox,
     Switch,
     Dropdown(DropdownOptions),
+    MultiSelect,
+    RadioGroup(RadioGroupOptions),
     DatePicker,
+    DateRangePicker(DateRangePickerOptions),
+    TimePicker(TimePickerOptions),
+    DatetimePicker(DatetimePickerOptions),
+    ColorPicker,
+    Slider(SliderOptions),
+    Nested,
+    List(ListOptions),
     Custom(CustomOptions),
+    /// The component picked by the field type's `GpuiFormDefaultComponent`
+    /// impl, only given by `#[gpui_form(infer)]`.
+    #[darling(skip)]
+    Inferred,
+}
+
+impl Components {
+    /// Picks the component `#[gpui_form(infer)]` gives a field of type `ty`,
+    /// looking through `Option<T>`.
+    ///
+    /// Strings get an input, `bool` a checkbox and `NaiveDate` a date picker.
+    /// Other plain paths, numbers and enums among them, can't be told apart
+    /// from their path and get [`Components::Inferred`], leaving the choice to
+    /// their `GpuiFormDefaultComponent` impl. Collections and other types with
+    /// generic arguments get `None`.
+    pub fn infer(ty: &syn::Type) -> Option<Self> {
+        let syn::Type::Path(type_path) = ty else {
+            return None;
+        };
+        if type_path.qself.is_some() {
+            return None;
+        }
+        let segment = type_path.path.segments.last()?;
+
+        if segment.ident == "Option" {
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            return match args.args.first()? {
+                syn::GenericArgument::Type(inner) => Self::infer(inner),
+                _ => None,
+            };
+        }
+        if !segment.arguments.is_none() {
+            return None;
+        }
+
+        let component = match segment.ident.to_string().as_str() {
+            "String" => Components::Input(InputOptions::default()),
+            "bool" => Components::Checkbox,
+            "NaiveDate" => Components::DatePicker,
+            _ => Components::Inferred,
+        };
+        Some(component)
+    }
 }
 
 #[derive(Clone, Debug, Display, EnumString, Eq, IntoStaticStr, PartialEq)]
 #[strum(serialize_all = "snake_case")]
 pub enum ComponentsBehaviour {
-    Input,
+    Input(BehaviourInputOptions),
+    Textarea(BehaviourTextareaOptions),
+    Otp(BehaviourOtpOptions),
     NumberInput,
     Checkbox,
     Switch,
     Dropdown(BehaviourDropdownOptions),
+    MultiSelect,
+    RadioGroup(BehaviourRadioGroupOptions),
     DatePicker,
+    DateRangePicker(BehaviourDateRangePickerOptions),
+    TimePicker(BehaviourTimePickerOptions),
+    DatetimePicker(BehaviourDatetimePickerOptions),
+    ColorPicker,
+    Slider(BehaviourSliderOptions),
+    Nested,
+    List(BehaviourListOptions),
+    /// Picks the variant of a `GpuiForm` enum.
+    #[strum(to_string = "selector")]
+    VariantSelector,
+    /// Sub-form of a `GpuiForm` enum variant carrying data.
+    Variant,
 }
 
 impl ComponentsBehaviour {
     pub fn as_component_ident(&self) -> proc_macro2::TokenStream {
         match self {
-            ComponentsBehaviour::Input => quote! { TextInput },
+            ComponentsBehaviour::Input(_) => quote! { TextInput },
+            ComponentsBehaviour::Textarea(_) => quote! { TextInput },
+            ComponentsBehaviour::Otp(_) => quote! { OtpInput },
             ComponentsBehaviour::NumberInput => quote! { NumberInput },
             ComponentsBehaviour::Checkbox => quote! { Checkbox },
             ComponentsBehaviour::Switch => quote! { Switch },
             ComponentsBehaviour::Dropdown(_) => quote! { Dropdown },
+            ComponentsBehaviour::MultiSelect => quote! { Dropdown },
+            ComponentsBehaviour::RadioGroup(_) => quote! { RadioGroup },
             ComponentsBehaviour::DatePicker => quote! { DatePicker },
+            ComponentsBehaviour::DateRangePicker(_) => quote! { DatePicker },
+            ComponentsBehaviour::TimePicker(_) => quote! { TextInput },
+            ComponentsBehaviour::DatetimePicker(_) => quote! { DatePicker },
+            ComponentsBehaviour::ColorPicker => quote! { ColorPicker },
+            ComponentsBehaviour::Slider(_) => quote! { Slider },
+            ComponentsBehaviour::Nested => quote! { GroupBox },
+            ComponentsBehaviour::List(options) => match options.item {
+                ListItem::Input => quote! { TextInput },
+                ListItem::NumberInput => quote! { NumberInput },
+                ListItem::Nested => quote! { GroupBox },
+            },
+            ComponentsBehaviour::VariantSelector => quote! { Dropdown },
+            ComponentsBehaviour::Variant => quote! { GroupBox },
         }
     }
 
     pub fn is_value_only_field(&self) -> bool {
         matches!(
             self,
-            ComponentsBehaviour::Checkbox | ComponentsBehaviour::Switch
+            ComponentsBehaviour::Checkbox
+                | ComponentsBehaviour::Switch
+                | ComponentsBehaviour::RadioGroup(_)
         )
     }
 
@@ -145,21 +520,45 @@ impl ComponentsBehaviour {
         }
     }
 
+    pub fn masked(&self) -> bool {
+        match self {
+            ComponentsBehaviour::Input(options) => options.masked,
+            ComponentsBehaviour::Otp(options) => options.masked,
+            _ => false,
+        }
+    }
+
     pub fn subscribable(&self) -> bool {
         matches!(
             self,
-            ComponentsBehaviour::Input
+            ComponentsBehaviour::Input(_)
+                | ComponentsBehaviour::Textarea(_)
+                | ComponentsBehaviour::Otp(_)
                 | ComponentsBehaviour::NumberInput
+                | ComponentsBehaviour::TimePicker(_)
+                | ComponentsBehaviour::DatetimePicker(_)
                 | ComponentsBehaviour::Dropdown(_)
+                | ComponentsBehaviour::MultiSelect
+                | ComponentsBehaviour::List(_)
+                | ComponentsBehaviour::VariantSelector
+                | ComponentsBehaviour::ColorPicker
+                | ComponentsBehaviour::Slider(_)
         )
     }
 
     pub fn focusable(&self) -> bool {
         matches!(
             self,
-            ComponentsBehaviour::Input
+            ComponentsBehaviour::Input(_)
+                | ComponentsBehaviour::Textarea(_)
+                | ComponentsBehaviour::Otp(_)
                 | ComponentsBehaviour::NumberInput
+                | ComponentsBehaviour::TimePicker(_)
+                | ComponentsBehaviour::DatetimePicker(_)
                 | ComponentsBehaviour::Dropdown(_)
+                | ComponentsBehaviour::MultiSelect
+                | ComponentsBehaviour::List(_)
+                | ComponentsBehaviour::VariantSelector
         )
     }
 }
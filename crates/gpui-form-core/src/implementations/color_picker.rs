@@ -0,0 +1,114 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl super::ComponentLayout for ColorPickerComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        use __crate_paths::gpui::{Context, Entity, Hsla, Window};
+        use __crate_paths::gpui_component::color_picker::ColorPickerState;
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#ColorPickerState>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(value: Option<#Hsla>, window: &mut #Window, cx: &mut #Context<'_, #ColorPickerState>) -> #ColorPickerState {
+                let state = #ColorPickerState::new(window, cx);
+                match value {
+                    Some(value) => state.default_value(value),
+                    None => state,
+                }
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(None, window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::color_picker::ColorPickerEvent;
+
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = *color; }
+        } else {
+            quote! {
+                if let Some(color) = color {
+                    data(this).#value_ident = *color;
+                }
+            }
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, _state, event: &#ColorPickerEvent, _window, _cx| match event {
+                    #ColorPickerEvent::Change(color) => {
+                        #value_assign
+                    },
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        let set_value = quote! {
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_value(color, window, cx));
+        };
+
+        if self.0.optional {
+            Some(quote! {
+                if let Some(color) = holder.#value_ident {
+                    #set_value
+                }
+            })
+        } else {
+            Some(quote! {
+                let color = holder.#value_ident;
+                #set_value
+            })
+        }
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        let value_assign = if self.0.optional {
+            quote! { holder.#value_ident = color; }
+        } else {
+            quote! {
+                if let Some(color) = color {
+                    holder.#value_ident = color;
+                }
+            }
+        };
+
+        Some(quote! {
+            let color = self.#field_name_ident.read(cx).value();
+            #value_assign
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use super::__crate_paths;
 use crate::components::*;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl super::ComponentLayout for DatePickerComponent {
     fn field_tokens(
@@ -9,13 +9,7 @@ impl super::ComponentLayout for DatePickerComponent {
         field_structure_tokens: &mut TokenStream,
         field_base_declarations_tokens: &mut TokenStream,
     ) {
-        let FieldInformation::<DatePickerOptions> {
-            options: _,
-            name,
-            r#type: _,
-        } = &self.0;
-
-        let field_name_ident = crate::component_field_name!(name);
+        let field_name_ident = crate::component_field_name!(&self.0.name);
 
         use __crate_paths::gpui::{Context, Entity, Window};
         use __crate_paths::gpui_component::date_picker::DatePickerState;
@@ -33,4 +27,86 @@ impl super::ComponentLayout for DatePickerComponent {
         field_structure_tokens.extend(field_structure_definition);
         field_base_declarations_tokens.extend(field_base_declaration);
     }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::date_picker::DatePickerEvent;
+
+        // `Date` displays as `%Y-%m-%d`, which chrono and jiff dates both parse
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = date.to_string().parse().ok(); }
+        } else {
+            quote! {
+                if let Ok(value) = date.to_string().parse() {
+                    data(this).#value_ident = value;
+                }
+            }
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, _state, event: &#DatePickerEvent, _window, _cx| match event {
+                    #DatePickerEvent::Change(date) => {
+                        #value_assign
+                    },
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::calendar::Date;
+
+        let date = if self.0.optional {
+            quote! {
+                holder
+                    .#value_ident
+                    .as_ref()
+                    .and_then(|date| date.to_string().parse().ok())
+            }
+        } else {
+            quote! { holder.#value_ident.to_string().parse().ok() }
+        };
+
+        Some(quote! {
+            let date = #Date::Single(#date);
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_date(date, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        let value_assign = if self.0.optional {
+            quote! { holder.#value_ident = date.to_string().parse().ok(); }
+        } else {
+            quote! {
+                if let Ok(value) = date.to_string().parse() {
+                    holder.#value_ident = value;
+                }
+            }
+        };
+
+        Some(quote! {
+            let date = self.#field_name_ident.read(cx).date();
+            #value_assign
+        })
+    }
 }
@@ -0,0 +1,146 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Expression turning `date`, the `Date` of a range picker, into an `Option` of the field's
+/// range, `None` until both bounds are picked.
+fn range_tokens(date: TokenStream) -> TokenStream {
+    use __crate_paths::gpui_component::calendar::Date;
+
+    quote! {
+        match #date {
+            #Date::Range(Some(start), Some(end)) => {
+                match (start.to_string().parse(), end.to_string().parse()) {
+                    (Ok(start), Ok(end)) => {
+                        Some(::gpui_form::time::DateRange::from_bounds(start, end))
+                    },
+                    _ => None,
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+impl super::ComponentLayout for DateRangePickerComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let presets_fn_ident = format_ident!("{}_presets", field_name_ident.0);
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+        use __crate_paths::gpui_component::date_picker::{DatePickerState, DateRangePreset};
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#DatePickerState>,
+        };
+
+        let presets_declaration = if let Some(presets) = &self.0.options.presets {
+            quote! {
+                pub fn #presets_fn_ident() -> Vec<#DateRangePreset> {
+                    #presets()
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #DatePickerState>) -> #DatePickerState {
+                #DatePickerState::range(window, cx)
+            }
+
+            #presets_declaration
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let range = range_tokens(quote! { date });
+
+        use __crate_paths::gpui_component::date_picker::DatePickerEvent;
+
+        // an optional range is cleared with the picker, a required one keeps its last value
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = #range; }
+        } else {
+            quote! {
+                if let Some(range) = #range {
+                    data(this).#value_ident = range;
+                }
+            }
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, _state, event: &#DatePickerEvent, _window, _cx| match event {
+                    #DatePickerEvent::Change(date) => {
+                        #value_assign
+                    },
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::calendar::Date;
+
+        let range = if self.0.optional {
+            quote! { holder.#value_ident.as_ref() }
+        } else {
+            quote! { Some(&holder.#value_ident) }
+        };
+
+        // the bounds go through their `%Y-%m-%d` display, as for date pickers
+        Some(quote! {
+            let date = match #range.map(::gpui_form::time::DateRange::bounds) {
+                Some((start, end)) => #Date::Range(
+                    start.to_string().parse().ok(),
+                    end.to_string().parse().ok(),
+                ),
+                None => #Date::Range(None, None),
+            };
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_date(date, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let range = range_tokens(quote! { self.#field_name_ident.read(cx).date() });
+
+        let value_assign = if self.0.optional {
+            quote! { holder.#value_ident = #range; }
+        } else {
+            quote! {
+                if let Some(range) = #range {
+                    holder.#value_ident = range;
+                }
+            }
+        };
+
+        Some(value_assign)
+    }
+}
@@ -0,0 +1,193 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl DatetimePickerComponent {
+    /// Statements binding `value` to the datetime made by the date and time states, reached with
+    /// `prefix`, `None` while they don't make one. A zoned value keeps the time zone of the one
+    /// `holder` has.
+    fn value_tokens(&self, holder: &TokenStream, prefix: &TokenStream) -> TokenStream {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let date_ident = format_ident!("{}_date", field_name.0);
+        let value_ident = format_ident!("{}", self.0.name);
+        let behaviour = &self.0.options.behaviour;
+        let zoned = behaviour.zoned;
+        let read_parts =
+            super::time_picker::read_time_part_tokens(&field_name.0, behaviour.seconds, prefix);
+
+        use __crate_paths::gpui_component::calendar::Date;
+
+        let replacing = if self.0.optional {
+            quote! { #holder.#value_ident.as_ref().map(|value| value.to_string()) }
+        } else {
+            quote! { Some(#holder.#value_ident.to_string()) }
+        };
+
+        quote! {
+            #read_parts
+            let value = match #prefix #date_ident.read(cx).date() {
+                #Date::Single(Some(date)) => {
+                    let replacing = #replacing;
+                    ::gpui_form::time::parse_datetime(
+                        &date.to_string(),
+                        time,
+                        #zoned,
+                        replacing.as_deref(),
+                    )
+                },
+                _ => None,
+            };
+        }
+    }
+}
+
+impl super::ComponentLayout for DatetimePickerComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let date_ident = format_ident!("{}_date", field_name.0);
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+        use __crate_paths::gpui_component::date_picker::DatePickerState;
+
+        let field_structure_definition = quote! {
+            pub #date_ident: #Entity<#DatePickerState>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #date_ident(window: &mut #Window, cx: &mut #Context<'_, #DatePickerState>) -> #DatePickerState {
+                #DatePickerState::new(window, cx)
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+
+        super::time_picker::time_part_tokens(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+            field_structure_tokens,
+            field_base_declarations_tokens,
+        );
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let date_ident = format_ident!("{}_date", field_name.0);
+        let time_initializers = super::time_picker::time_part_initializers(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+        );
+
+        Some(quote! {
+            #date_ident: cx.new(|cx| Self::#date_ident(window, cx)),
+            #time_initializers
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let date_ident = format_ident!("{}_date", field_name.0);
+        let value_ident = format_ident!("{}", self.0.name);
+        let seconds = self.0.options.behaviour.seconds;
+        let value = self.value_tokens(&quote! { data(this) }, &quote! {});
+
+        use __crate_paths::gpui_component::date_picker::DatePickerEvent;
+
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = Some(value); }
+        } else {
+            quote! { data(this).#value_ident = value; }
+        };
+
+        // the value only follows the date and time while they make a datetime
+        let sync = quote! {
+            #value
+            if let Some(value) = value {
+                #value_assign
+            }
+        };
+
+        let captures: Vec<syn::Ident> = std::iter::once(date_ident.clone())
+            .chain(super::time_picker::time_part_idents(&field_name.0, seconds))
+            .collect();
+        let time_subscriptions = super::time_picker::time_part_subscription_tokens(
+            &field_name.0,
+            seconds,
+            &captures,
+            &sync,
+        );
+
+        Some(quote! {
+            {
+                #(let #captures = self.#captures.clone();)*
+                subscriptions.push(cx.subscribe_in(
+                    &self.#date_ident,
+                    window,
+                    move |this, _state, event: &#DatePickerEvent, _window, cx| match event {
+                        #DatePickerEvent::Change(_) => {
+                            #sync
+                        },
+                    },
+                ));
+            }
+            #time_subscriptions
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let date_ident = format_ident!("{}_date", field_name.0);
+        let value_ident = format_ident!("{}", self.0.name);
+        let load_parts = super::time_picker::load_time_part_tokens(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+        );
+
+        use __crate_paths::gpui_component::calendar::Date;
+
+        let value = if self.0.optional {
+            quote! { holder.#value_ident.as_ref().map(|value| value.to_string()) }
+        } else {
+            quote! { Some(holder.#value_ident.to_string()) }
+        };
+
+        // chrono and jiff datetimes display as `YYYY-MM-DD` and `HH:MM:SS`, split by a `T` or
+        // a space, followed by their time zone when they have one
+        Some(quote! {
+            let value = #value;
+            let (date, parts) = value
+                .as_deref()
+                .and_then(::gpui_form::time::datetime_parts)
+                .unzip();
+            let date = #Date::Single(date.and_then(|date| date.parse().ok()));
+            self.#date_ident
+                .update(cx, |state, cx| state.set_date(date, window, cx));
+            #load_parts
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let value_ident = format_ident!("{}", self.0.name);
+        let value = self.value_tokens(&quote! { holder }, &quote! { self. });
+
+        let value_assign = if self.0.optional {
+            quote! { holder.#value_ident = value; }
+        } else {
+            quote! {
+                if let Some(value) = value {
+                    holder.#value_ident = value;
+                }
+            }
+        };
+
+        Some(quote! {
+            #value
+            #value_assign
+        })
+    }
+}
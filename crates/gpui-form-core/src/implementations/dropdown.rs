@@ -1,7 +1,7 @@
 use super::__crate_paths;
 use crate::components::*;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl super::ComponentLayout for DropdownComponent {
     fn field_tokens(
@@ -9,19 +9,15 @@ impl super::ComponentLayout for DropdownComponent {
         field_structure_tokens: &mut TokenStream,
         field_base_declarations_tokens: &mut TokenStream,
     ) {
-        let FieldInformation::<DropdownOptions> {
-            options,
-            name,
-            r#type,
-        } = &self.0;
+        let r#type = &self.0.r#type;
 
-        let field_name_ident = crate::component_field_name!(name);
+        let field_name_ident = crate::component_field_name!(&self.0.name);
 
         use __crate_paths::gpui::{Context, Entity, Window};
         use __crate_paths::gpui_component::IndexPath;
         use __crate_paths::gpui_component::dropdown::{DropdownState, SearchableVec};
 
-        let vec_type = if options.behaviour.searchable {
+        let vec_type = if self.0.options.behaviour.searchable {
             quote! { #SearchableVec }
         } else {
             quote! { Vec }
@@ -35,7 +31,7 @@ impl super::ComponentLayout for DropdownComponent {
             pub #field_name_ident: #Entity<#state_type>,
         };
 
-        let index = if let Some(named_index) = options.named_index() {
+        let index = if let Some(named_index) = self.0.options.named_index() {
             let path = named_index.clone();
             quote! {
               Some(
@@ -46,7 +42,7 @@ impl super::ComponentLayout for DropdownComponent {
                 )
               )
             }
-        } else if options.index_default() {
+        } else if self.0.options.index_default() {
             quote! {
               Some(
                 #IndexPath::new(
@@ -60,7 +56,7 @@ impl super::ComponentLayout for DropdownComponent {
             quote! { None }
         };
 
-        let field_base_declaration = if !options.behaviour.partial {
+        let field_base_declaration = if !self.0.options.behaviour.partial {
             quote! {
                 pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #state_type>) -> #state_type {
                   use strum::IntoEnumIterator as _;
@@ -74,4 +70,69 @@ impl super::ComponentLayout for DropdownComponent {
         field_structure_tokens.extend(field_structure_definition);
         field_base_declarations_tokens.extend(field_base_declaration);
     }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        if self.0.options.behaviour.partial {
+            return None;
+        }
+
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let r#type = &self.0.r#type;
+
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::dropdown::{DropdownEvent, SearchableVec};
+
+        let vec_type = if self.0.options.behaviour.searchable {
+            quote! { #SearchableVec }
+        } else {
+            quote! { Vec }
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, _state, event: &#DropdownEvent<#vec_type<#r#type>>, _window, _cx| {
+                    match event {
+                        #DropdownEvent::Confirm(value) => {
+                            if let Some(value) = value {
+                                data(this).#value_ident = value.clone().into();
+                            }
+                        },
+                    }
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        Some(quote! {
+            let value = ::core::convert::Into::into(::core::clone::Clone::clone(&holder.#value_ident));
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_selected_value(&value, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        Some(quote! {
+            if let Some(value) = self.#field_name_ident.read(cx).selected_value() {
+                holder.#value_ident = value.clone().into();
+            }
+        })
+    }
 }
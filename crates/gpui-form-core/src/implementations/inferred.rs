@@ -0,0 +1,86 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl super::ComponentLayout for InferredComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let r#type = &self.0.r#type;
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+
+        let state_type = quote! {
+            <#r#type as ::gpui_form::GpuiFormDefaultComponent>::State
+        };
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#state_type>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #state_type>) -> #state_type {
+                <#r#type as ::gpui_form::GpuiFormDefaultComponent>::build_state(window, cx)
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        Some(quote! {
+            subscriptions.extend(<#r#type as ::gpui_form::GpuiFormDefaultComponent>::subscribe(
+                &self.#field_name_ident,
+                window,
+                cx,
+                move |this| &mut data(this).#value_ident,
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        Some(quote! {
+            <#r#type as ::gpui_form::GpuiFormDefaultComponent>::load(
+                &self.#field_name_ident,
+                &holder.#value_ident,
+                window,
+                cx,
+            );
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        Some(quote! {
+            <#r#type as ::gpui_form::GpuiFormDefaultComponent>::read(
+                &self.#field_name_ident,
+                &mut holder.#value_ident,
+                cx,
+            );
+        })
+    }
+}
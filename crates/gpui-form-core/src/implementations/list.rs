@@ -0,0 +1,347 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Type of the rows of a list, an input state or the fields of a nested form.
+fn row_type_tokens(options: &ListOptions, r#type: &syn::Ident) -> TokenStream {
+    use __crate_paths::gpui::Entity;
+    use __crate_paths::gpui_component::input::InputState;
+
+    match options.behaviour.item {
+        ListItem::Input | ListItem::NumberInput => quote! { #Entity<#InputState> },
+        ListItem::Nested => {
+            let nested_fields_ident = format_ident!("{}FormFields", r#type);
+            quote! { #nested_fields_ident }
+        },
+    }
+}
+
+/// Expression building the state of a row, with `window` and `cx` in scope, the latter a
+/// `Context<'_, InputState>` for inputs and an `App` for nested forms.
+fn row_state_tokens(options: &ListOptions, r#type: &syn::Ident) -> TokenStream {
+    use __crate_paths::gpui_component::input::InputState;
+
+    match options.behaviour.item {
+        ListItem::Input => quote! { #InputState::new(window, cx) },
+        ListItem::NumberInput => quote! {
+            {
+                use ::gpui_form::NumRegex;
+                #InputState::new(window, cx).pattern(#r#type::validation_regex().clone())
+            }
+        },
+        ListItem::Nested => {
+            let nested_components_ident = format_ident!("{}FormComponents", r#type);
+            quote! { #nested_components_ident::build_fields(window, cx) }
+        },
+    }
+}
+
+impl super::ComponentLayout for ListComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let row_fn_ident = format_ident!("{}_row", field_name_ident.0);
+        let push_fn_ident = format_ident!("{}_push", field_name_ident.0);
+        let remove_fn_ident = format_ident!("{}_remove", field_name_ident.0);
+        let move_fn_ident = format_ident!("{}_move", field_name_ident.0);
+
+        use __crate_paths::gpui::{App, AppContext, Context, Window};
+        use __crate_paths::gpui_component::input::InputState;
+
+        let row_type = row_type_tokens(&self.0.options, &self.0.r#type);
+        let row_state = row_state_tokens(&self.0.options, &self.0.r#type);
+
+        let (row_declaration, new_row) = match self.0.options.behaviour.item {
+            ListItem::Input | ListItem::NumberInput => (
+                quote! {
+                    pub fn #row_fn_ident(window: &mut #Window, cx: &mut #Context<'_, #InputState>) -> #InputState {
+                        #row_state
+                    }
+                },
+                quote! {
+                    use #AppContext as _;
+                    rows.push(cx.new(|cx| Self::#row_fn_ident(window, cx)));
+                },
+            ),
+            ListItem::Nested => (
+                quote! {
+                    pub fn #row_fn_ident(window: &mut #Window, cx: &mut #App) -> #row_type {
+                        #row_state
+                    }
+                },
+                quote! {
+                    rows.push(Self::#row_fn_ident(window, cx));
+                },
+            ),
+        };
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: Vec<#row_type>,
+        };
+
+        let field_base_declaration = quote! {
+            #row_declaration
+
+            pub fn #push_fn_ident<'a>(rows: &'a mut Vec<#row_type>, window: &mut #Window, cx: &mut #App) -> &'a #row_type {
+                #new_row
+                &rows[rows.len() - 1]
+            }
+
+            pub fn #remove_fn_ident(rows: &mut Vec<#row_type>, index: usize) -> Option<#row_type> {
+                (index < rows.len()).then(|| rows.remove(index))
+            }
+
+            pub fn #move_fn_ident(rows: &mut Vec<#row_type>, from: usize, to: usize) -> bool {
+                if from >= rows.len() || to >= rows.len() {
+                    return false;
+                }
+                let row = rows.remove(from);
+                rows.insert(to, row);
+                true
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: Vec::new(),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        use __crate_paths::gpui_component::input::InputEvent;
+
+        let values = if self.0.optional {
+            quote! { data(this).#value_ident.get_or_insert_default() }
+        } else {
+            quote! { &mut data(this).#value_ident }
+        };
+
+        // each row writes to the value at its index, so rows pushed, removed or moved after
+        // subscribing need subscribing again
+        let row_subscription = match self.0.options.behaviour.item {
+            ListItem::Input | ListItem::NumberInput => {
+                let value = if let ListItem::Input = self.0.options.behaviour.item {
+                    quote! { Some(state.read(cx).value().to_string().into()) }
+                } else {
+                    quote! { state.read(cx).value().parse::<#r#type>().ok() }
+                };
+                quote! {
+                    subscriptions.push(cx.subscribe_in(
+                        row,
+                        window,
+                        move |this, state, event: &#InputEvent, _window, cx| {
+                            if let #InputEvent::Change = event
+                                && let Some(value) = #value
+                                && let Some(slot) = (#values).get_mut(index)
+                            {
+                                *slot = value;
+                            }
+                        },
+                    ));
+                }
+            },
+            ListItem::Nested => quote! {
+                subscriptions.extend(row.subscribe(window, cx, move |this| {
+                    let values = #values;
+                    if values.len() <= index {
+                        values.resize_with(index + 1, ::core::default::Default::default);
+                    }
+                    &mut values[index]
+                }));
+            },
+        };
+
+        Some(quote! {
+            for (index, row) in self.#field_name_ident.iter().enumerate() {
+                #row_subscription
+            }
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let row_state = row_state_tokens(&self.0.options, &self.0.r#type);
+
+        use __crate_paths::gpui::AppContext;
+
+        let values = if self.0.optional {
+            quote! { holder.#value_ident.iter().flatten() }
+        } else {
+            quote! { holder.#value_ident.iter() }
+        };
+
+        let (new_row, load_row) = match self.0.options.behaviour.item {
+            ListItem::Input => (
+                quote! { cx.new(|cx| #row_state) },
+                quote! {
+                    let value: ::std::string::String =
+                        ::core::convert::Into::into(::core::clone::Clone::clone(value));
+                    row.update(cx, |state, cx| state.set_value(value, window, cx));
+                },
+            ),
+            ListItem::NumberInput => (
+                quote! { cx.new(|cx| #row_state) },
+                quote! {
+                    let value = value.to_string();
+                    row.update(cx, |state, cx| state.set_value(value, window, cx));
+                },
+            ),
+            ListItem::Nested => (row_state, quote! { row.load(value, window, cx); }),
+        };
+
+        // rows are kept where there is a value for them, so only missing ones are built
+        Some(quote! {
+            use #AppContext as _;
+            let values = #values.collect::<Vec<_>>();
+            let rows = &mut self.#field_name_ident;
+            rows.truncate(values.len());
+            while rows.len() < values.len() {
+                rows.push(#new_row);
+            }
+            for (row, value) in rows.iter_mut().zip(values) {
+                #load_row
+            }
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        let (values, assign) = if self.0.optional {
+            (
+                quote! { holder.#value_ident.get_or_insert_default() },
+                quote! { holder.#value_ident = Some(values); },
+            )
+        } else {
+            (
+                quote! { &mut holder.#value_ident },
+                quote! { holder.#value_ident = values; },
+            )
+        };
+
+        // number rows are only written back once they all parse, as for number inputs
+        let read = match self.0.options.behaviour.item {
+            ListItem::Input => quote! {
+                let values = rows
+                    .iter()
+                    .map(|row| row.read(cx).value().to_string().into())
+                    .collect::<Vec<#r#type>>();
+                #assign
+            },
+            ListItem::NumberInput => quote! {
+                if let Ok(values) = rows
+                    .iter()
+                    .map(|row| row.read(cx).value().parse::<#r#type>())
+                    .collect::<Result<Vec<#r#type>, _>>()
+                {
+                    #assign
+                }
+            },
+            ListItem::Nested => quote! {
+                let values = #values;
+                values.resize_with(rows.len(), ::core::default::Default::default);
+                for (row, value) in rows.iter().zip(values) {
+                    row.read(value, cx);
+                }
+            },
+        };
+
+        // an optional field left `None` stays so until a row is added
+        let read = if self.0.optional {
+            quote! {
+                if !rows.is_empty() || holder.#value_ident.is_some() {
+                    #read
+                }
+            }
+        } else {
+            read
+        };
+
+        Some(quote! {
+            let rows = &self.#field_name_ident;
+            #read
+        })
+    }
+
+    fn state_tokens(&self, components: &TokenStream) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let push_fn_ident = format_ident!("{}_push", field_name_ident.0);
+        let remove_fn_ident = format_ident!("{}_remove", field_name_ident.0);
+        let move_fn_ident = format_ident!("{}_move", field_name_ident.0);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui::{Context, Window};
+
+        let values = if self.0.optional {
+            quote! { self.current.#value_ident.get_or_insert_default() }
+        } else {
+            quote! { &mut self.current.#value_ident }
+        };
+
+        // the values are edited along with the rows, which are then subscribed to again at
+        // their new indexes
+        Some(quote! {
+            /// Adds a row at the end, with a default value if `current` has none for it yet.
+            pub fn #push_fn_ident(&mut self, window: &mut #Window, cx: &mut #Context<Self>) {
+                #components::#push_fn_ident(&mut self.fields.#field_name_ident, window, cx);
+                let values = #values;
+                let rows = self.fields.#field_name_ident.len();
+                if values.len() < rows {
+                    values.resize_with(rows, ::core::default::Default::default);
+                }
+                self.resubscribe(window, cx);
+            }
+
+            /// Removes the row at `index` along with its value, doing nothing past the last row.
+            pub fn #remove_fn_ident(&mut self, index: usize, window: &mut #Window, cx: &mut #Context<Self>) {
+                if #components::#remove_fn_ident(&mut self.fields.#field_name_ident, index).is_none() {
+                    return;
+                }
+                let values = #values;
+                if index < values.len() {
+                    values.remove(index);
+                }
+                self.resubscribe(window, cx);
+            }
+
+            /// Moves the row at `from` to `to` along with its value, doing nothing if either is
+            /// past the last row.
+            pub fn #move_fn_ident(
+                &mut self,
+                from: usize,
+                to: usize,
+                window: &mut #Window,
+                cx: &mut #Context<Self>,
+            ) {
+                if !#components::#move_fn_ident(&mut self.fields.#field_name_ident, from, to) {
+                    return;
+                }
+                let values = #values;
+                let rows = self.fields.#field_name_ident.len();
+                if values.len() < rows {
+                    values.resize_with(rows, ::core::default::Default::default);
+                }
+                let value = values.remove(from);
+                values.insert(to, value);
+                self.resubscribe(window, cx);
+            }
+        })
+    }
+}
@@ -1,12 +1,24 @@
 use proc_macro2::TokenStream;
 
 pub mod checkbox;
+pub mod color_picker;
 pub mod custom;
 pub mod date_picker;
+pub mod date_range_picker;
+pub mod datetime_picker;
 pub mod dropdown;
+pub mod inferred;
 pub mod input;
+pub mod list;
+pub mod multi_select;
+pub mod nested;
 pub mod number_input;
+pub mod otp;
+pub mod radio_group;
+pub mod slider;
 pub mod switch;
+pub mod textarea;
+pub mod time_picker;
 
 pub trait ComponentLayout {
     fn field_tokens(
@@ -14,6 +26,45 @@ pub trait ComponentLayout {
         field_structure_tokens: &mut TokenStream,
         field_base_declarations_tokens: &mut TokenStream,
     );
+
+    /// Initializers of the `XFormFields` entries emitted by `field_tokens`, used by the
+    /// generated `build_fields`. `None` when the states can't be built without user code.
+    fn field_initializer_tokens(&self) -> Option<TokenStream>;
+
+    /// Subscriptions keeping the value holder returned by `data(this)` in sync with the
+    /// states emitted by `field_tokens`, pushed onto `subscriptions` by the generated
+    /// `XFormFields::subscribe`. `None` when there is no state to follow, the value of
+    /// checkboxes, switches and radio groups being set by the form's own click handlers.
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        None
+    }
+
+    /// Methods of the `XFormState` entity editing the states emitted by `field_tokens`,
+    /// `components` being the `XFormComponents` type they were declared on. They keep
+    /// `self.current` in step and end with `self.resubscribe(window, cx)`.
+    fn state_tokens(&self, _components: &TokenStream) -> Option<TokenStream> {
+        None
+    }
+
+    /// Statements setting the states emitted by `field_tokens` to the values of `holder`, run
+    /// by the generated `XFormFields::load`. `None` for the same fields as
+    /// `subscription_tokens`.
+    fn load_tokens(&self) -> Option<TokenStream> {
+        None
+    }
+
+    /// Statements writing the values of the states emitted by `field_tokens` back to
+    /// `holder`, run by the generated `XFormFields::read`.
+    fn read_tokens(&self) -> Option<TokenStream> {
+        None
+    }
+
+    /// Bounds `load_tokens` and `read_tokens` need on the field's type, required of it by
+    /// `load` and `read` when it is a type parameter of the form.
+    fn value_bounds(&self) -> Option<TokenStream> {
+        None
+    }
 }
 
-mod __crate_paths;
+#[doc(hidden)]
+pub mod __crate_paths;
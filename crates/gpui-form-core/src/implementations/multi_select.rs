@@ -0,0 +1,163 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Ident of the entity holding the selected values of a multi select, next to its dropdown.
+fn selected_ident(field_name_ident: &crate::names::ComponentFieldName) -> syn::Ident {
+    format_ident!("{}_selected", field_name_ident.0)
+}
+
+/// Statements offering the items of `r#type` that `selected` doesn't hold in `dropdown`,
+/// clearing its selection so the next pick confirms again.
+fn offer_tokens(r#type: &syn::Ident) -> TokenStream {
+    quote! {
+        use strum::IntoEnumIterator as _;
+        let items = #r#type::iter()
+            .filter(|item| !selected.contains(item))
+            .collect::<Vec<#r#type>>();
+        dropdown.update(cx, |state, cx| {
+            state.set_items(items.into(), window, cx);
+            state.set_selected_index(None, window, cx);
+        });
+    }
+}
+
+impl super::ComponentLayout for MultiSelectComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let r#type = &self.0.r#type;
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let selected_ident = selected_ident(&field_name_ident);
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+        use __crate_paths::gpui_component::dropdown::{DropdownState, SearchableVec};
+
+        let state_type = quote! {
+          #DropdownState<#SearchableVec<#r#type>>
+        };
+
+        // the dropdown picks one value at a time, the ones picked so far are kept next to it
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#state_type>,
+            pub #selected_ident: #Entity<Vec<#r#type>>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #state_type>) -> #state_type {
+              use strum::IntoEnumIterator as _;
+              #DropdownState::new(#r#type::iter().collect::<Vec<#r#type>>().into(), None, window, cx)
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let selected_ident = selected_ident(&field_name_ident);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+            #selected_ident: cx.new(|_| Vec::new()),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let r#type = &self.0.r#type;
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let selected_ident = selected_ident(&field_name_ident);
+        let value_ident = format_ident!("{}", self.0.name);
+        let offer = offer_tokens(r#type);
+
+        use __crate_paths::gpui_component::dropdown::{DropdownEvent, SearchableVec};
+
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = Some(values); }
+        } else {
+            quote! { data(this).#value_ident = values; }
+        };
+
+        // picks are added to the selected values, whose changes, picks or removals by the
+        // render, are written to the value holder and taken out of the dropdown
+        Some(quote! {
+            let selected = self.#selected_ident.clone();
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |_this, _state, event: &#DropdownEvent<#SearchableVec<#r#type>>, _window, cx| {
+                    match event {
+                        #DropdownEvent::Confirm(value) => {
+                            if let Some(value) = value {
+                                selected.update(cx, |selected, cx| {
+                                    if !selected.contains(value) {
+                                        selected.push(value.clone());
+                                        cx.notify();
+                                    }
+                                });
+                            }
+                        },
+                    }
+                },
+            ));
+            let dropdown = self.#field_name_ident.clone();
+            subscriptions.push(cx.observe_in(
+                &self.#selected_ident,
+                window,
+                move |this, selected, window, cx| {
+                    let selected = selected.read(cx).clone();
+                    {
+                        #offer
+                    }
+                    let values = selected.into_iter().collect();
+                    #value_assign
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let selected_ident = selected_ident(&field_name_ident);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+        let offer = offer_tokens(r#type);
+
+        let values = if self.0.optional {
+            quote! { holder.#value_ident.iter().flatten() }
+        } else {
+            quote! { holder.#value_ident.iter() }
+        };
+
+        Some(quote! {
+            let selected = #values.cloned().collect::<Vec<#r#type>>();
+            let dropdown = &self.#field_name_ident;
+            #offer
+            self.#selected_ident.update(cx, |values, _cx| *values = selected);
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let selected_ident = selected_ident(&field_name_ident);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        // an optional field left `None` stays so until something is selected
+        if self.0.optional {
+            Some(quote! {
+                let selected = self.#selected_ident.read(cx);
+                if !selected.is_empty() || holder.#value_ident.is_some() {
+                    holder.#value_ident = Some(selected.iter().cloned().collect());
+                }
+            })
+        } else {
+            Some(quote! {
+                holder.#value_ident = self.#selected_ident.read(cx).iter().cloned().collect();
+            })
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl super::ComponentLayout for NestedComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let nested_fields_ident = format_ident!("{}FormFields", &self.0.r#type);
+        let nested_components_ident = format_ident!("{}FormComponents", &self.0.r#type);
+
+        use __crate_paths::gpui::{App, Window};
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #nested_fields_ident,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(window: &mut #Window, cx: &mut #App) -> #nested_fields_ident {
+                #nested_components_ident::build_fields(window, cx)
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: Self::#field_name_ident(window, cx),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        let value_holder = if self.0.optional {
+            quote! { data(this).#value_ident.get_or_insert_default() }
+        } else {
+            quote! { &mut data(this).#value_ident }
+        };
+
+        Some(quote! {
+            subscriptions.extend(self.#field_name_ident.subscribe(
+                window,
+                cx,
+                move |this| #value_holder,
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        if self.0.optional {
+            Some(quote! {
+                if let Some(value) = &holder.#value_ident {
+                    self.#field_name_ident.load(value, window, cx);
+                }
+            })
+        } else {
+            Some(quote! {
+                self.#field_name_ident.load(&holder.#value_ident, window, cx);
+            })
+        }
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        if self.0.optional {
+            Some(quote! {
+                if let Some(value) = &mut holder.#value_ident {
+                    self.#field_name_ident.read(value, cx);
+                }
+            })
+        } else {
+            Some(quote! {
+                self.#field_name_ident.read(&mut holder.#value_ident, cx);
+            })
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use super::__crate_paths;
 use crate::components::*;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl super::ComponentLayout for NumberInputComponent {
     fn field_tokens(
@@ -9,13 +9,8 @@ impl super::ComponentLayout for NumberInputComponent {
         field_structure_tokens: &mut TokenStream,
         field_base_declarations_tokens: &mut TokenStream,
     ) {
-        let FieldInformation::<NumberInputOptions> {
-            options: _,
-            name,
-            r#type,
-        } = &self.0;
-
-        let field_name_ident = crate::component_field_name!(name);
+        let r#type = &self.0.r#type;
+        let field_name_ident = crate::component_field_name!(&self.0.name);
 
         use __crate_paths::gpui::{Context, Entity, Window};
         use __crate_paths::gpui_component::input::InputState;
@@ -24,14 +19,112 @@ impl super::ComponentLayout for NumberInputComponent {
             pub #field_name_ident: #Entity<#InputState>,
         };
 
+        let placeholder = self
+            .0
+            .placeholder
+            .as_ref()
+            .map(|placeholder| quote! { .placeholder(#placeholder) });
+
         let field_base_declaration = quote! {
             pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #InputState>) -> #InputState {
               use ::gpui_form::NumRegex;
-                #InputState::new(window, cx).pattern(#r#type::validation_regex().clone())
+                #InputState::new(window, cx).pattern(#r#type::validation_regex().clone()) #placeholder
             }
         };
 
         field_structure_tokens.extend(field_structure_definition);
         field_base_declarations_tokens.extend(field_base_declaration);
     }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let r#type = &self.0.r#type;
+
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::input::{InputEvent, NumberInputEvent, StepAction};
+
+        let type_name = r#type.to_string();
+        let (decrement, increment) = if type_name.starts_with('f') {
+            (
+                quote! { *value - 1 as #r#type },
+                quote! { *value + 1 as #r#type },
+            )
+        } else if type_name.starts_with('u') || type_name.starts_with('i') {
+            (
+                quote! { value.saturating_sub(1 as #r#type) },
+                quote! { value.saturating_add(1 as #r#type) },
+            )
+        } else {
+            // external types (assuming they impl `impl_saturating!`)
+            (
+                quote! { value.saturating_sub(#r#type::from(1)) },
+                quote! { value.saturating_add(#r#type::from(1)) },
+            )
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, state, event: &#InputEvent, _window, cx| {
+                    if let #InputEvent::Change = event {
+                        if let Ok(value) = state.read(cx).value().parse::<#r#type>() {
+                            data(this).#value_ident = value.into();
+                        }
+                    }
+                },
+            ));
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, state, event: &#NumberInputEvent, window, cx| match event {
+                    #NumberInputEvent::Step(step_action) => {
+                        let value = &mut data(this).#value_ident;
+                        *value = match step_action {
+                            #StepAction::Decrement => #decrement,
+                            #StepAction::Increment => #increment,
+                        };
+                        let text = value.to_string();
+                        state.update(cx, |input, cx| input.set_value(text, window, cx));
+                    },
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        Some(quote! {
+            let value = holder.#value_ident.to_string();
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_value(value, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        Some(quote! {
+            if let Ok(value) = self.#field_name_ident.read(cx).value().parse::<#r#type>() {
+                holder.#value_ident = value.into();
+            }
+        })
+    }
+
+    fn value_bounds(&self) -> Option<TokenStream> {
+        Some(quote! { ::std::string::ToString + ::core::str::FromStr })
+    }
 }
@@ -0,0 +1,93 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl super::ComponentLayout for OtpComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+        use __crate_paths::gpui_component::input::OtpState;
+
+        let length = self.0.options.behaviour.length;
+        let masked = if self.0.options.behaviour.masked {
+            quote! { .masked(true) }
+        } else {
+            quote! {}
+        };
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#OtpState>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(window: &mut #Window, cx: &mut #Context<'_, #OtpState>) -> #OtpState {
+                #OtpState::new(#length, window, cx) #masked
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::input::InputEvent;
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, state, event: &#InputEvent, _window, cx| {
+                    if let #InputEvent::Change = event {
+                        data(this).#value_ident = state.read(cx).value().to_string().into();
+                    }
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        Some(quote! {
+            let value: ::std::string::String =
+                ::core::convert::Into::into(::core::clone::Clone::clone(&holder.#value_ident));
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_value(value, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        Some(quote! {
+            holder.#value_ident = self.#field_name_ident.read(cx).value().to_string().into();
+        })
+    }
+
+    fn value_bounds(&self) -> Option<TokenStream> {
+        Some(quote! {
+            ::core::clone::Clone
+                + ::core::convert::Into<::std::string::String>
+                + ::core::convert::From<::std::string::String>
+        })
+    }
+}
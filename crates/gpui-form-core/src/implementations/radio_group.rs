@@ -0,0 +1,28 @@
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+impl super::ComponentLayout for RadioGroupComponent {
+    fn field_tokens(
+        &self,
+        _field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let r#type = &self.0.r#type;
+
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident() -> Vec<#r#type> {
+                use strum::IntoEnumIterator as _;
+                #r#type::iter().collect::<Vec<#r#type>>()
+            }
+        };
+
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        Some(TokenStream::new())
+    }
+}
@@ -0,0 +1,107 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl super::ComponentLayout for SliderComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        use __crate_paths::gpui::{Context, Entity, Window};
+        use __crate_paths::gpui_component::slider::SliderState;
+
+        let SliderOptions { min, max, step } = &self.0.options;
+        let min = min.as_ref().map(|min| quote! { .min((#min) as f32) });
+        let max = max.as_ref().map(|max| quote! { .max((#max) as f32) });
+        let step = step.as_ref().map(|step| quote! { .step((#step) as f32) });
+
+        let field_structure_definition = quote! {
+            pub #field_name_ident: #Entity<#SliderState>,
+        };
+
+        let field_base_declaration = quote! {
+            pub fn #field_name_ident(_window: &mut #Window, _cx: &mut #Context<'_, #SliderState>) -> #SliderState {
+                #SliderState::new() #min #max #step
+            }
+        };
+
+        field_structure_tokens.extend(field_structure_definition);
+        field_base_declarations_tokens.extend(field_base_declaration);
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+
+        Some(quote! {
+            #field_name_ident: cx.new(|cx| Self::#field_name_ident(window, cx)),
+        })
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let r#type = &self.0.r#type;
+
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+
+        use __crate_paths::gpui_component::slider::SliderEvent;
+
+        // `SliderValue` is always `f32`, other numeric types need a cast
+        let value = if r#type == "f32" {
+            quote! { value.start() }
+        } else {
+            quote! { value.start() as #r#type }
+        };
+
+        Some(quote! {
+            subscriptions.push(cx.subscribe_in(
+                &self.#field_name_ident,
+                window,
+                move |this, _state, event: &#SliderEvent, _window, cx| match event {
+                    #SliderEvent::Change(value) => {
+                        data(this).#value_ident = #value;
+                        cx.notify();
+                    },
+                },
+            ));
+        })
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        let value = if r#type == "f32" {
+            quote! { holder.#value_ident }
+        } else {
+            quote! { holder.#value_ident as f32 }
+        };
+
+        Some(quote! {
+            let value = #value;
+            self.#field_name_ident
+                .update(cx, |state, cx| state.set_value(value, window, cx));
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name_ident = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let r#type = &self.0.r#type;
+
+        let value = if r#type == "f32" {
+            quote! { value.start() }
+        } else {
+            quote! { value.start() as #r#type }
+        };
+
+        Some(quote! {
+            let value = self.#field_name_ident.read(cx).value();
+            holder.#value_ident = #value;
+        })
+    }
+}
@@ -7,10 +7,9 @@ impl super::ComponentLayout for SwitchComponent {
         _field_structure_tokens: &mut TokenStream,
         _field_base_declarations_tokens: &mut TokenStream,
     ) {
-        let FieldInformation::<SwitchOptions> {
-            options: _,
-            name: _,
-            r#type: _,
-        } = &self.0;
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        Some(TokenStream::new())
     }
 }
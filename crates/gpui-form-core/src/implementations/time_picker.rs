@@ -0,0 +1,237 @@
+use super::__crate_paths;
+use crate::components::*;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Hour, minute and optionally second inputs shared by the time and datetime pickers.
+pub(super) fn time_part_tokens(
+    field_name: &str,
+    seconds: bool,
+    field_structure_tokens: &mut TokenStream,
+    field_base_declarations_tokens: &mut TokenStream,
+) {
+    use __crate_paths::gpui::{Context, Entity, Window};
+    use __crate_paths::gpui_component::input::InputState;
+
+    let mut parts = vec![("hour", "HH"), ("minute", "MM")];
+    if seconds {
+        parts.push(("second", "SS"));
+    }
+
+    for (part, placeholder) in parts {
+        let part_ident = format_ident!("{}_{}", field_name, part);
+
+        field_structure_tokens.extend(quote! {
+            pub #part_ident: #Entity<#InputState>,
+        });
+
+        field_base_declarations_tokens.extend(quote! {
+            pub fn #part_ident(window: &mut #Window, cx: &mut #Context<'_, #InputState>) -> #InputState {
+                #InputState::new(window, cx)
+                    .placeholder(#placeholder)
+                    .pattern(::gpui_form::time::part_regex().clone())
+            }
+        });
+    }
+}
+
+/// Idents of the hour, minute and optionally second inputs emitted by [`time_part_tokens`].
+pub(super) fn time_part_idents(field_name: &str, seconds: bool) -> Vec<syn::Ident> {
+    let mut parts = vec!["hour", "minute"];
+    if seconds {
+        parts.push("second");
+    }
+
+    parts
+        .into_iter()
+        .map(|part| format_ident!("{}_{}", field_name, part))
+        .collect()
+}
+
+/// `build_fields` initializers matching [`time_part_tokens`].
+pub(super) fn time_part_initializers(field_name: &str, seconds: bool) -> TokenStream {
+    let initializers = time_part_idents(field_name, seconds)
+        .into_iter()
+        .map(|part_ident| {
+            quote! {
+                #part_ident: cx.new(|cx| Self::#part_ident(window, cx)),
+            }
+        });
+
+    quote! { #(#initializers)* }
+}
+
+/// Statements setting the inputs emitted by [`time_part_tokens`] to `parts`, an
+/// `Option<[&str; 3]>` of the hour, minute and second, emptying them without one.
+pub(super) fn load_time_part_tokens(field_name: &str, seconds: bool) -> TokenStream {
+    let loads = time_part_idents(field_name, seconds)
+        .into_iter()
+        .enumerate()
+        .map(|(index, part_ident)| {
+            quote! {
+                let part = parts[#index].to_owned();
+                self.#part_ident
+                    .update(cx, |state, cx| state.set_value(part, window, cx));
+            }
+        });
+
+    quote! {
+        let parts: [&str; 3] = parts.unwrap_or_default();
+        #(#loads)*
+    }
+}
+
+/// Statement binding `time` to the values of the inputs emitted by [`time_part_tokens`], reached
+/// with `prefix`, as the `[&str; 3]` `gpui_form::time` parses, an input left out reading as empty.
+pub(super) fn read_time_part_tokens(
+    field_name: &str,
+    seconds: bool,
+    prefix: &TokenStream,
+) -> TokenStream {
+    use __crate_paths::gpui::Entity;
+    use __crate_paths::gpui_component::input::InputState;
+
+    let values = time_part_idents(field_name, seconds)
+        .into_iter()
+        .map(|part_ident| quote! { part(&#prefix #part_ident) })
+        .chain(std::iter::repeat_with(
+            || quote! { ::std::string::String::new() },
+        ))
+        .take(3);
+
+    quote! {
+        let part = |state: &#Entity<#InputState>| state.read(cx).value().to_string();
+        let [hour, minute, second] = [#(#values),*];
+        let time = [hour.as_str(), minute.as_str(), second.as_str()];
+    }
+}
+
+/// Subscriptions running `sync` whenever one of the inputs emitted by [`time_part_tokens`]
+/// changes, with clones of the `captures` states bound to their own idents for it to read.
+pub(super) fn time_part_subscription_tokens(
+    field_name: &str,
+    seconds: bool,
+    captures: &[syn::Ident],
+    sync: &TokenStream,
+) -> TokenStream {
+    use __crate_paths::gpui_component::input::InputEvent;
+
+    let subscriptions = time_part_idents(field_name, seconds)
+        .into_iter()
+        .map(|part_ident| {
+            quote! {
+                {
+                    #(let #captures = self.#captures.clone();)*
+                    subscriptions.push(cx.subscribe_in(
+                        &self.#part_ident,
+                        window,
+                        move |this, _state, event: &#InputEvent, _window, cx| {
+                            if let #InputEvent::Change = event {
+                                #sync
+                            }
+                        },
+                    ));
+                }
+            }
+        });
+
+    quote! { #(#subscriptions)* }
+}
+
+impl super::ComponentLayout for TimePickerComponent {
+    fn field_tokens(
+        &self,
+        field_structure_tokens: &mut TokenStream,
+        field_base_declarations_tokens: &mut TokenStream,
+    ) {
+        let field_name = crate::component_field_name!(&self.0.name);
+
+        time_part_tokens(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+            field_structure_tokens,
+            field_base_declarations_tokens,
+        );
+    }
+
+    fn field_initializer_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+
+        Some(time_part_initializers(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+        ))
+    }
+
+    fn subscription_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let seconds = self.0.options.behaviour.seconds;
+        let read_parts = read_time_part_tokens(&field_name.0, seconds, &quote! {});
+
+        let value_assign = if self.0.optional {
+            quote! { data(this).#value_ident = Some(value); }
+        } else {
+            quote! { data(this).#value_ident = value; }
+        };
+
+        // the value only follows the inputs while they make a time
+        let sync = quote! {
+            #read_parts
+            if let Some(value) = ::gpui_form::time::parse_time(time) {
+                #value_assign
+            }
+        };
+
+        Some(time_part_subscription_tokens(
+            &field_name.0,
+            seconds,
+            &time_part_idents(&field_name.0, seconds),
+            &sync,
+        ))
+    }
+
+    fn load_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let load_parts = load_time_part_tokens(&field_name.0, self.0.options.behaviour.seconds);
+
+        let value = if self.0.optional {
+            quote! { holder.#value_ident.as_ref().map(|value| value.to_string()) }
+        } else {
+            quote! { Some(holder.#value_ident.to_string()) }
+        };
+
+        // chrono and jiff times display as `HH:MM:SS`
+        Some(quote! {
+            let value = #value;
+            let parts = value.as_deref().and_then(::gpui_form::time::time_parts);
+            #load_parts
+        })
+    }
+
+    fn read_tokens(&self) -> Option<TokenStream> {
+        let field_name = crate::component_field_name!(&self.0.name);
+        let value_ident = format_ident!("{}", self.0.name);
+        let read_parts = read_time_part_tokens(
+            &field_name.0,
+            self.0.options.behaviour.seconds,
+            &quote! { self. },
+        );
+
+        let value_assign = if self.0.optional {
+            quote! { holder.#value_ident = ::gpui_form::time::parse_time(time); }
+        } else {
+            quote! {
+                if let Some(value) = ::gpui_form::time::parse_time(time) {
+                    holder.#value_ident = value;
+                }
+            }
+        };
+
+        Some(quote! {
+            #read_parts
+            #value_assign
+        })
+    }
+}
@@ -1,4 +1,4 @@
 pub mod components;
 pub mod implementations;
-mod names;
+pub mod names;
 pub mod registry;
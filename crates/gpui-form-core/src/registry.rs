@@ -6,6 +6,17 @@ inventory::collect!(GpuiFormShape);
 pub struct GpuiFormShape {
     pub struct_name: &'static str,
     pub components: &'static [FieldVariant],
+    /// The enum this shape is a variant of, variant shapes only being rendered by their enum.
+    pub variant_of: Option<&'static str>,
+    /// Arguments of a generic struct's registered instantiation, e.g. `u32` for `Settings<u32>`.
+    pub generic_args: Option<&'static str>,
+    /// Whether the value holder has a `validate_fields`, generated for garde-validated structs.
+    pub validated: bool,
+    /// Whether the value holder has a `validate`, generated for `validate(...)` field rules and
+    /// `validate_with`.
+    pub checked: bool,
+    /// Whether the value holder has a `changed_fields`, generated for `track_changes` structs.
+    pub tracked: bool,
 }
 
 impl GpuiFormShape {
@@ -13,8 +24,44 @@ impl GpuiFormShape {
         Self {
             struct_name,
             components,
+            variant_of: None,
+            generic_args: None,
+            validated: false,
+            checked: false,
+            tracked: false,
         }
     }
+
+    pub const fn variant_of(mut self, enum_name: &'static str) -> Self {
+        self.variant_of = Some(enum_name);
+        self
+    }
+
+    pub const fn with_generic_args(mut self, generic_args: &'static str) -> Self {
+        self.generic_args = Some(generic_args);
+        self
+    }
+
+    pub const fn validated(mut self) -> Self {
+        self.validated = true;
+        self
+    }
+
+    pub const fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+
+    pub const fn tracked(mut self) -> Self {
+        self.tracked = true;
+        self
+    }
+
+    pub fn find(struct_name: &str) -> Option<&'static GpuiFormShape> {
+        inventory::iter::<GpuiFormShape>
+            .into_iter()
+            .find(|shape| shape.struct_name == struct_name)
+    }
 }
 
 #[derive(Debug)]
@@ -23,6 +70,26 @@ pub struct FieldVariant {
     pub field_type: &'static str,
     pub optional: bool,
     pub behaviour: ComponentsBehaviour,
+    pub element_type: Option<&'static str>,
+    pub nested_shape: Option<&'static str>,
+    /// Whether `behaviour` comes from the type's `GpuiFormDefaultComponent` impl.
+    pub inferred: bool,
+    pub label: Option<&'static str>,
+    pub description: Option<&'static str>,
+    pub placeholder: Option<&'static str>,
+    pub validation: Option<FieldValidation>,
+}
+
+/// Rules of a field's `#[gpui_form(validate(...))]`, `min` and `max` being the source of
+/// their expressions.
+#[derive(Debug)]
+pub struct FieldValidation {
+    pub required: bool,
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    pub min: Option<&'static str>,
+    pub max: Option<&'static str>,
+    pub pattern: Option<&'static str>,
 }
 
 impl FieldVariant {
@@ -37,8 +104,66 @@ impl FieldVariant {
             field_type,
             optional,
             behaviour,
+            element_type: None,
+            nested_shape: None,
+            inferred: false,
+            label: None,
+            description: None,
+            placeholder: None,
+            validation: None,
         }
     }
+    /// Records the item type of a collection field, e.g. `Permission` for `Vec<Permission>`.
+    pub const fn with_element_type(mut self, element_type: &'static str) -> Self {
+        self.element_type = Some(element_type);
+        self
+    }
+    /// Records the struct whose `GpuiFormShape` a `component(nested)` field renders.
+    pub const fn with_nested_shape(mut self, struct_name: &'static str) -> Self {
+        self.nested_shape = Some(struct_name);
+        self
+    }
+    /// Marks a field whose component `#[gpui_form(infer)]` left to its type.
+    pub const fn with_inferred_component(mut self) -> Self {
+        self.inferred = true;
+        self
+    }
+    /// Literal label, shown instead of the field's Fluent label.
+    pub const fn with_label(mut self, label: &'static str) -> Self {
+        self.label = Some(label);
+        self
+    }
+    /// Literal description, shown instead of the field's Fluent description.
+    pub const fn with_description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Placeholder of the field's input.
+    pub const fn with_placeholder(mut self, placeholder: &'static str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+    /// Validation rules of the field.
+    pub const fn with_validation(mut self, validation: FieldValidation) -> Self {
+        self.validation = Some(validation);
+        self
+    }
+    pub fn nested_shape(&self) -> Option<&'static GpuiFormShape> {
+        self.nested_shape.and_then(GpuiFormShape::find)
+    }
+    /// Suffix of the field's entries in `XFormFields` and `XFormComponents`, `inferred` for
+    /// inferred components since the derive can't name them after a component it doesn't know.
+    pub fn component_suffix(&self) -> String {
+        if self.inferred {
+            "inferred".to_owned()
+        } else {
+            self.behaviour.to_string()
+        }
+    }
+    pub fn element_ty(&self) -> Option<syn::Type> {
+        self.element_type
+            .map(|element_type| syn::parse_str(element_type).unwrap())
+    }
     pub fn full_type(&self) -> syn::Type {
         let mut ty = syn::parse_str(self.field_type).unwrap();
         if self.optional {
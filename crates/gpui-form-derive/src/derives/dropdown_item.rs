@@ -7,6 +7,7 @@ use syn::DeriveInput;
 #[darling(supports(enum_any))]
 struct DropdownItemArgs {
     ident: syn::Ident,
+    data: darling::ast::Data<syn::Variant, ()>,
 }
 
 pub fn from(input: TokenStream) -> TokenStream {
@@ -19,6 +20,78 @@ pub fn from(input: TokenStream) -> TokenStream {
 
     let item_ident = &args.ident;
 
+    // Enums of unit variants list their items themselves, so `#[gpui_form(infer)]` can give
+    // them a dropdown.
+    let default_component = args.data.take_enum().and_then(|variants| {
+        variants
+            .iter()
+            .all(|variant| variant.fields.is_empty())
+            .then(|| {
+                let variant_idents = variants.iter().map(|variant| &variant.ident);
+                quote! {
+                    impl ::gpui_form::GpuiFormDefaultComponent for #item_ident {
+                        type State = gpui_component::dropdown::DropdownState<Vec<Self>>;
+
+                        const BEHAVIOUR: ::gpui_form::core::components::ComponentsBehaviour =
+                            ::gpui_form::core::components::ComponentsBehaviour::Dropdown(
+                                ::gpui_form::core::components::BehaviourDropdownOptions {
+                                    searchable: false,
+                                    partial: false,
+                                }
+                            );
+
+                        fn build_state(
+                            window: &mut gpui::Window,
+                            cx: &mut gpui::Context<'_, Self::State>,
+                        ) -> Self::State {
+                            gpui_component::dropdown::DropdownState::new(
+                                vec![#(Self::#variant_idents),*],
+                                None,
+                                window,
+                                cx,
+                            )
+                        }
+
+                        fn subscribe<S: 'static>(
+                            state: &gpui::Entity<Self::State>,
+                            window: &mut gpui::Window,
+                            cx: &mut gpui::Context<S>,
+                            value: impl Fn(&mut S) -> &mut Self + Copy + 'static,
+                        ) -> Vec<gpui::Subscription> {
+                            vec![cx.subscribe_in(
+                                state,
+                                window,
+                                move |this, _state, event: &gpui_component::dropdown::DropdownEvent<Vec<Self>>, _window, _cx| {
+                                    match event {
+                                        gpui_component::dropdown::DropdownEvent::Confirm(picked) => {
+                                            if let Some(picked) = picked {
+                                                *value(this) = ::core::clone::Clone::clone(picked);
+                                            }
+                                        },
+                                    }
+                                },
+                            )]
+                        }
+
+                        fn load(
+                            state: &gpui::Entity<Self::State>,
+                            value: &Self,
+                            window: &mut gpui::Window,
+                            cx: &mut gpui::App,
+                        ) {
+                            state.update(cx, |state, cx| state.set_selected_value(value, window, cx));
+                        }
+
+                        fn read(state: &gpui::Entity<Self::State>, value: &mut Self, cx: &gpui::App) {
+                            if let Some(selected) = state.read(cx).selected_value() {
+                                *value = ::core::clone::Clone::clone(selected);
+                            }
+                        }
+                    }
+                }
+            })
+    });
+
     let expanded = quote! {
         impl gpui_component::dropdown::DropdownItem for #item_ident {
             type Value = Self;
@@ -31,6 +104,8 @@ pub fn from(input: TokenStream) -> TokenStream {
                 self
             }
         }
+
+        #default_component
     };
 
     expanded.into()
@@ -0,0 +1,369 @@
+use gpui_form_core::implementations::__crate_paths;
+use heck::{ToSnakeCase as _, ToTitleCase as _};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use super::{
+    ComponentStruct, ComponentVariant, GpuiFormOptions, form_components, form_impl, form_state,
+    shape_expr, shape_registration, value_holder,
+};
+
+/// An enum becomes a variant selector plus one sub-form per variant carrying data, every
+/// variant keeping its own values in the value holder while another one is selected. With
+/// `entity`, the selector and the sub-forms are subscribed to as a struct's fields are.
+pub(super) fn enum_form(
+    parsed: &ComponentStruct,
+    variants: &[ComponentVariant],
+    options: &GpuiFormOptions,
+) -> darling::Result<TokenStream> {
+    use __crate_paths::gpui::{
+        App, AppContext, Context, Entity, SharedString, Subscription, Window,
+    };
+    use __crate_paths::gpui_component::IndexPath;
+    use __crate_paths::gpui_component::dropdown::{DropdownEvent, DropdownItem, DropdownState};
+
+    let enum_name = &parsed.ident;
+
+    let Some(first_variant) = variants.first() else {
+        return Err(
+            darling::Error::custom("GpuiForm needs at least one enum variant").with_span(enum_name),
+        );
+    };
+
+    let mut errors = darling::Error::accumulator();
+    for field in variants.iter().flat_map(|variant| &variant.fields.fields) {
+        if let (Some(field_ident), Some(_)) = (&field.ident, &field.validate) {
+            errors.push(
+                darling::Error::custom("`validate` is not supported on enum variant fields")
+                    .with_span(field_ident),
+            );
+        }
+        if let (Some(field_ident), Some(_)) = (&field.ident, &field.validate_async) {
+            errors.push(
+                darling::Error::custom("`validate_async` is not supported on enum variant fields")
+                    .with_span(field_ident),
+            );
+        }
+    }
+    errors.finish()?;
+
+    let variant_enum_name = format_ident!("{}FormVariant", enum_name);
+    let value_holder_name = format_ident!("{}FormValueHolder", enum_name);
+    let components_holder_name = format_ident!("{}FormFields", enum_name);
+    let components_base_declarations_name = format_ident!("{}FormComponents", enum_name);
+
+    let first_variant_ident = &first_variant.ident;
+    let other_variant_idents: Vec<&Ident> =
+        variants[1..].iter().map(|variant| &variant.ident).collect();
+    let variant_idents: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+    let variant_titles: Vec<String> = variants
+        .iter()
+        .map(|variant| {
+            variant
+                .label
+                .clone()
+                .unwrap_or_else(|| variant.ident.to_string().to_title_case())
+        })
+        .collect();
+
+    let selector_state = quote! {
+        #DropdownState<Vec<#variant_enum_name>>
+    };
+    let variant_enum_name_str = variant_enum_name.to_string();
+
+    let mut variant_forms = Vec::new();
+    let mut holder_members = Vec::new();
+    let mut fields_members = Vec::new();
+    let mut variant_declarations = Vec::new();
+    let mut variant_initializers = Vec::new();
+    let mut variant_loads = Vec::new();
+    let mut variant_reads = Vec::new();
+    let mut variant_subscriptions = Vec::new();
+    let mut shape_variants = vec![quote! {
+        ::gpui_form::core::registry::FieldVariant::new(
+            "variant",
+            #variant_enum_name_str,
+            false,
+            ::gpui_form::core::components::ComponentsBehaviour::VariantSelector
+        )
+    }];
+    let mut into_holder_arms = Vec::new();
+    let mut from_holder_arms = Vec::new();
+    let mut has_build_fields = true;
+
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let fields = &variant.fields.fields;
+
+        if fields.is_empty() {
+            into_holder_arms.push(quote! {
+                #enum_name::#variant_ident => Self {
+                    variant: #variant_enum_name::#variant_ident,
+                    ..::core::default::Default::default()
+                },
+            });
+            from_holder_arms.push(quote! {
+                #variant_enum_name::#variant_ident => #enum_name::#variant_ident,
+            });
+            continue;
+        }
+
+        let member = format_ident!("{}", variant_ident.to_string().to_snake_case());
+        if member == "variant" {
+            return Err(darling::Error::custom(
+                "a variant with fields can't be named `Variant`, its values would clash with the \
+                 `variant` field of the value holder",
+            )
+            .with_span(variant_ident));
+        }
+        let fields_member = format_ident!("{}_variant", member);
+        let form_name = format_ident!("{}{}", enum_name, variant_ident);
+        let variant_value_holder_name = format_ident!("{}FormValueHolder", form_name);
+        let variant_fields_name = format_ident!("{}FormFields", form_name);
+        let variant_components_name = format_ident!("{}FormComponents", form_name);
+
+        let content =
+            form_components(&form_name, &syn::Generics::default(), fields, parsed.entity)?;
+        let value_holder = value_holder(
+            &variant_value_holder_name,
+            &syn::Generics::default(),
+            fields,
+            &content.should_be_unwrapped,
+        )?;
+        let value_holder_tokens = &value_holder.tokens;
+
+        let form_components_tokens = &content.tokens;
+        let variant_shape = shape_registration(
+            options,
+            &shape_expr(&form_name, &content.field_variants, Some(enum_name), None),
+        );
+        variant_forms.push(quote! {
+            #value_holder_tokens
+
+            #form_components_tokens
+
+            #variant_shape
+        });
+
+        holder_members.push(quote! { pub #member: #variant_value_holder_name, });
+        fields_members.push(quote! { pub #fields_member: #variant_fields_name, });
+        variant_loads.push(quote! { self.#fields_member.load(&holder.#member, window, cx); });
+        variant_reads.push(quote! { self.#fields_member.read(&mut holder.#member, cx); });
+        variant_subscriptions.push(quote! {
+            subscriptions.extend(self.#fields_member.subscribe(
+                window,
+                cx,
+                move |this| &mut data(this).#member,
+            ));
+        });
+
+        if content.has_build_fields {
+            variant_declarations.push(quote! {
+                pub fn #fields_member(
+                    window: &mut #Window,
+                    cx: &mut #App,
+                ) -> #variant_fields_name {
+                    #variant_components_name::build_fields(window, cx)
+                }
+            });
+            variant_initializers.push(quote! {
+                #fields_member: Self::#fields_member(window, cx),
+            });
+        } else {
+            has_build_fields = false;
+        }
+
+        let member_str = member.to_string();
+        let variant_ident_str = variant_ident.to_string();
+        let form_name_str = form_name.to_string();
+        shape_variants.push(quote! {
+            ::gpui_form::core::registry::FieldVariant::new(
+                #member_str,
+                #variant_ident_str,
+                false,
+                ::gpui_form::core::components::ComponentsBehaviour::Variant
+            )
+            .with_nested_shape(#form_name_str)
+        });
+
+        let field_idents = &value_holder.field_idents;
+        let into_holder = value_holder.initializers(&value_holder.into_holder);
+        let from_holder = value_holder.initializers(&value_holder.from_holder);
+        into_holder_arms.push(quote! {
+            #enum_name::#variant_ident { #(#field_idents),* } => Self {
+                variant: #variant_enum_name::#variant_ident,
+                #member: #variant_value_holder_name {
+                    #(#into_holder,)*
+                },
+                ..::core::default::Default::default()
+            },
+        });
+        from_holder_arms.push(quote! {
+            #variant_enum_name::#variant_ident => {
+                let #variant_value_holder_name { #(#field_idents),* } = from.#member;
+                #enum_name::#variant_ident {
+                    #(#from_holder,)*
+                }
+            },
+        });
+    }
+
+    let build_fields = has_build_fields.then(|| {
+        quote! {
+            #[allow(unused_imports, unused_variables)]
+            pub fn build_fields(
+                window: &mut #Window,
+                cx: &mut #App,
+            ) -> #components_holder_name {
+                use #AppContext as _;
+                #components_holder_name {
+                    variant_selector: cx.new(|cx| Self::variant_selector(window, cx)),
+                    #(#variant_initializers)*
+                }
+            }
+        }
+    });
+
+    // switching variants only changes `variant`, the values of the others staying in the holder
+    let subscribe = parsed.entity.then(|| {
+        quote! {
+            impl #components_holder_name {
+                pub fn subscribe<S: 'static>(
+                    &self,
+                    window: &mut #Window,
+                    cx: &mut #Context<S>,
+                    data: impl Fn(&mut S) -> &mut #value_holder_name + Copy + 'static,
+                ) -> ::std::vec::Vec<#Subscription> {
+                    let mut subscriptions = ::std::vec::Vec::new();
+                    subscriptions.push(cx.subscribe_in(
+                        &self.variant_selector,
+                        window,
+                        move |this, _state, event: &#DropdownEvent<Vec<#variant_enum_name>>, _window, cx| {
+                            match event {
+                                #DropdownEvent::Confirm(variant) => {
+                                    if let Some(variant) = variant {
+                                        data(this).variant = *variant;
+                                        cx.notify();
+                                    }
+                                },
+                            }
+                        },
+                    ));
+                    #(#variant_subscriptions)*
+                    subscriptions
+                }
+            }
+        }
+    });
+    // the sub-forms' own state methods reach their fields through `self.fields`, which an enum
+    // nests under the variant, so only `resubscribe` is offered
+    let form_state = form_state(parsed, &[], has_build_fields, &[], &[])?;
+
+    let shape = shape_expr(enum_name, &shape_variants, None, None);
+    let shape_impl = shape_registration(options, &shape);
+    let form_impl = has_build_fields.then(|| form_impl(&syn::parse_quote! { #enum_name }, &shape));
+
+    Ok(quote! {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub enum #variant_enum_name {
+            #[default]
+            #first_variant_ident,
+            #(#other_variant_idents,)*
+        }
+
+        impl #DropdownItem for #variant_enum_name {
+            type Value = Self;
+
+            fn title(&self) -> #SharedString {
+                match self {
+                    #(Self::#variant_idents => #variant_titles.into(),)*
+                }
+            }
+
+            fn value(&self) -> &Self::Value {
+                self
+            }
+        }
+
+        #(#variant_forms)*
+
+        #[derive(Clone, Debug, Default)]
+        pub struct #value_holder_name {
+            pub variant: #variant_enum_name,
+            #(#holder_members)*
+        }
+
+        impl ::core::convert::From<#enum_name> for #value_holder_name {
+            fn from(from: #enum_name) -> Self {
+                match from {
+                    #(#into_holder_arms)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#value_holder_name> for #enum_name {
+            fn from(from: #value_holder_name) -> Self {
+                match from.variant {
+                    #(#from_holder_arms)*
+                }
+            }
+        }
+
+        pub struct #components_holder_name {
+            pub variant_selector: #Entity<#selector_state>,
+            #(#fields_members)*
+        }
+
+        impl #components_holder_name {
+            /// Sets the states of the fields to the values of `holder`.
+            pub fn load(
+                &mut self,
+                holder: &#value_holder_name,
+                window: &mut #Window,
+                cx: &mut #App,
+            ) {
+                self.variant_selector.update(cx, |state, cx| {
+                    state.set_selected_value(&holder.variant, window, cx)
+                });
+                #(#variant_loads)*
+            }
+
+            /// Writes the values of the states of the fields back to `holder`.
+            pub fn read(&self, holder: &mut #value_holder_name, cx: &#App) {
+                if let Some(variant) = self.variant_selector.read(cx).selected_value() {
+                    holder.variant = *variant;
+                }
+                #(#variant_reads)*
+            }
+        }
+
+        #subscribe
+
+        pub struct #components_base_declarations_name;
+
+        impl #components_base_declarations_name {
+            pub fn variant_selector(
+                window: &mut #Window,
+                cx: &mut #Context<'_, #selector_state>,
+            ) -> #selector_state {
+                #DropdownState::new(
+                    vec![#(#variant_enum_name::#variant_idents),*],
+                    Some(#IndexPath::new(0)),
+                    window,
+                    cx,
+                )
+            }
+
+            #(#variant_declarations)*
+
+            #build_fields
+        }
+
+        #shape_impl
+
+        #form_impl
+
+        #form_state
+    })
+}
@@ -0,0 +1,2070 @@
+mod enums;
+mod tuple;
+mod validation;
+
+use std::collections::HashMap;
+
+use darling::{FromDeriveInput, FromField, FromMeta, FromVariant, ast};
+use gpui_form_core::components::*;
+use gpui_form_core::implementations::{__crate_paths, ComponentLayout};
+use itertools::Itertools as _;
+use proc_macro2::TokenStream;
+use quote::{ToTokens as _, format_ident, quote};
+use syn::{
+    DeriveInput, GenericArgument, Ident, PathArguments, Token, Type, parse_macro_input,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+};
+
+use self::enums::enum_form;
+use self::tuple::{transparent_form, tuple_struct_form};
+use self::validation::{AsyncValidationField, async_validations, validate_fields, validate_rules};
+
+#[derive(Clone, Debug, FromField)]
+#[darling(
+    attributes(gpui_form),
+    forward_attrs(garde),
+    and_then = Self::record_type_options
+)]
+struct ComponentField {
+    pub ident: Option<Ident>,
+    pub ty: Type,
+    #[darling(default)]
+    pub component: Option<Components>,
+    #[darling(default)]
+    skip: bool,
+    /// Literal label, for forms not using Fluent.
+    #[darling(default)]
+    pub label: Option<String>,
+    /// Literal description, for forms not using Fluent.
+    #[darling(default)]
+    pub description: Option<String>,
+    #[darling(default)]
+    pub placeholder: Option<String>,
+    /// Rules checked by the generated `XFormValueHolder::validate`.
+    #[darling(default)]
+    pub validate: Option<ValidationRules>,
+    /// Function returning a future of the field's validity, run by the generated `XFormState`
+    /// when the field changes.
+    #[darling(default)]
+    pub validate_async: Option<syn::Path>,
+    /// Wait before `validate_async` runs, in milliseconds.
+    #[darling(default)]
+    pub debounce_ms: Option<u64>,
+    /// `#[garde(...)]` attributes, telling the struct is validated with garde.
+    pub attrs: Vec<syn::Attribute>,
+}
+
+impl ComponentField {
+    pub fn skip(&self) -> bool {
+        self.skip && self.component.is_none()
+    }
+
+    /// Fills the options of the component that follow from the field's type rather than the
+    /// attribute.
+    fn record_type_options(mut self) -> darling::Result<Self> {
+        if let Some(Components::DatetimePicker(options)) = &mut self.component {
+            options.behaviour.zoned = is_zoned(&self.ty);
+        }
+        Ok(self)
+    }
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(gpui_form))]
+struct ComponentVariant {
+    pub ident: Ident,
+    pub fields: ast::Fields<ComponentField>,
+    /// Title of the variant in the variant selector, its name in title case by default.
+    #[darling(default)]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(
+    attributes(gpui_form),
+    supports(struct_named, struct_newtype, struct_tuple, enum_named, enum_unit),
+    forward_attrs(garde),
+    and_then = Self::infer_components
+)]
+struct ComponentStruct {
+    pub ident: Ident,
+    pub generics: syn::Generics,
+    pub data: ast::Data<ComponentVariant, ComponentField>,
+    #[darling(default)]
+    pub register: RegisteredTypes,
+    #[darling(default)]
+    pub transparent: bool,
+    #[darling(default)]
+    pub infer: bool,
+    /// Whether an `XFormState` entity holding the struct and its synced fields is generated,
+    /// as in `#[gpui_form(entity)]`.
+    #[darling(default)]
+    pub entity: bool,
+    /// Function checking the value holder as a whole once its fields pass their rules, as in
+    /// `#[gpui_form(validate_with = Self::check)]`.
+    #[darling(default)]
+    pub validate_with: Option<syn::Path>,
+    /// Whether the value holder, and the form state of `entity` structs, get a
+    /// `changed_fields` and an `is_dirty` comparing them to the original struct, as in
+    /// `#[gpui_form(track_changes)]`.
+    #[darling(default)]
+    pub track_changes: bool,
+    pub attrs: Vec<syn::Attribute>,
+}
+
+impl ComponentStruct {
+    /// Gives every field without a `component(...)` or `skip` the component
+    /// picked by [`Components::infer`] when `#[gpui_form(infer)]` is set.
+    fn infer_components(mut self) -> darling::Result<Self> {
+        if !self.infer || self.transparent {
+            return Ok(self);
+        }
+
+        let fields: Vec<&mut ComponentField> = match &mut self.data {
+            ast::Data::Struct(fields) => fields.fields.iter_mut().collect(),
+            ast::Data::Enum(variants) => variants
+                .iter_mut()
+                .flat_map(|variant| variant.fields.fields.iter_mut())
+                .collect(),
+        };
+
+        let mut errors = darling::Error::accumulator();
+        for field in fields {
+            if field.component.is_some() || field.skip {
+                continue;
+            }
+            field.component = Components::infer(&field.ty);
+            if field.component.is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        "cannot infer a component for this type; add `component(...)` or `skip`",
+                    )
+                    .with_span(&field.ty),
+                );
+            }
+        }
+        errors.finish_with(self)
+    }
+}
+
+/// Instantiations of a generic struct to register a `GpuiFormShape` for, as in
+/// `#[gpui_form(register(Settings<u32>, Settings<f64>))]`.
+#[derive(Debug, Default)]
+struct RegisteredTypes(Vec<Type>);
+
+impl FromMeta for RegisteredTypes {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let types = item
+            .require_list()?
+            .parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+        Ok(Self(types.into_iter().collect()))
+    }
+}
+
+fn get_components_behaviour_tokens(component: &Components, base_type: &Type) -> TokenStream {
+    match component {
+        Components::Input(options) => {
+            let masked = options.behaviour.masked;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::Input(
+                    ::gpui_form::core::components::BehaviourInputOptions {
+                        masked: #masked,
+                    }
+                )
+            }
+        },
+        Components::Textarea(options) => {
+            let rows = options.behaviour.rows;
+            let auto_grow = options.behaviour.auto_grow;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::Textarea(
+                    ::gpui_form::core::components::BehaviourTextareaOptions {
+                        rows: #rows,
+                        auto_grow: #auto_grow,
+                    }
+                )
+            }
+        },
+        Components::Otp(options) => {
+            let length = options.behaviour.length;
+            let masked = options.behaviour.masked;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::Otp(
+                    ::gpui_form::core::components::BehaviourOtpOptions {
+                        length: #length,
+                        masked: #masked,
+                    }
+                )
+            }
+        },
+        Components::NumberInput => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::NumberInput }
+        },
+        Components::Checkbox => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::Checkbox }
+        },
+        Components::Switch => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::Switch }
+        },
+        Components::Dropdown(options) => {
+            let searchable = options.behaviour.searchable;
+            let partial = options.behaviour.partial;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::Dropdown(
+                    ::gpui_form::core::components::BehaviourDropdownOptions {
+                        searchable: #searchable,
+                        partial: #partial,
+                    }
+                )
+            }
+        },
+        Components::MultiSelect => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::MultiSelect }
+        },
+        Components::RadioGroup(options) => {
+            let orientation = match options.orientation() {
+                Orientation::Horizontal => quote! { Horizontal },
+                Orientation::Vertical => quote! { Vertical },
+            };
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::RadioGroup(
+                    ::gpui_form::core::components::BehaviourRadioGroupOptions {
+                        orientation: ::gpui_form::core::components::Orientation::#orientation,
+                    }
+                )
+            }
+        },
+        Components::DatePicker => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::DatePicker }
+        },
+        Components::DateRangePicker(options) => {
+            let presets = options.presets.is_some();
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::DateRangePicker(
+                    ::gpui_form::core::components::BehaviourDateRangePickerOptions {
+                        presets: #presets,
+                    }
+                )
+            }
+        },
+        Components::TimePicker(options) => {
+            let seconds = options.behaviour.seconds;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::TimePicker(
+                    ::gpui_form::core::components::BehaviourTimePickerOptions {
+                        seconds: #seconds,
+                    }
+                )
+            }
+        },
+        Components::DatetimePicker(options) => {
+            let seconds = options.behaviour.seconds;
+            let zoned = options.behaviour.zoned;
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::DatetimePicker(
+                    ::gpui_form::core::components::BehaviourDatetimePickerOptions {
+                        seconds: #seconds,
+                        zoned: #zoned,
+                    }
+                )
+            }
+        },
+        Components::ColorPicker => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::ColorPicker }
+        },
+        Components::Slider(options) => {
+            let bound = |expr: &Option<Box<syn::Expr>>| match expr {
+                Some(expr) => {
+                    let expr = expr.to_token_stream().to_string();
+                    quote! { Some(#expr) }
+                },
+                None => quote! { None },
+            };
+            let min = bound(&options.min);
+            let max = bound(&options.max);
+            let step = bound(&options.step);
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::Slider(
+                    ::gpui_form::core::components::BehaviourSliderOptions {
+                        min: #min,
+                        max: #max,
+                        step: #step,
+                    }
+                )
+            }
+        },
+        Components::Nested => {
+            quote! { ::gpui_form::core::components::ComponentsBehaviour::Nested }
+        },
+        Components::List(options) => {
+            let item = match options.behaviour.item {
+                ListItem::Input => quote! { Input },
+                ListItem::NumberInput => quote! { NumberInput },
+                ListItem::Nested => quote! { Nested },
+            };
+            quote! {
+                ::gpui_form::core::components::ComponentsBehaviour::List(
+                    ::gpui_form::core::components::BehaviourListOptions {
+                        item: ::gpui_form::core::components::ListItem::#item,
+                    }
+                )
+            }
+        },
+        Components::Custom(custom_options) => {
+            let component_ident = &custom_options.behaviour.name;
+            quote! { #component_ident }
+        },
+        Components::Inferred => {
+            quote! { <#base_type as ::gpui_form::GpuiFormDefaultComponent>::BEHAVIOUR }
+        },
+    }
+}
+
+struct ComponentFieldContent {
+    field_structure_tokens: TokenStream,
+    field_base_declarations_tokens: TokenStream,
+    field_initializer_tokens: Option<TokenStream>,
+    subscription_tokens: Option<TokenStream>,
+    state_tokens: Option<TokenStream>,
+    load_tokens: Option<TokenStream>,
+    read_tokens: Option<TokenStream>,
+    /// The field's type and the bounds `load` and `read` need on it.
+    value_bounds: Option<(Ident, TokenStream)>,
+    should_be_unwrapped: (String, bool),
+}
+
+/// Everything [`ComponentLayout`] generates for `component`, the component of `field_name`,
+/// along with whether the value holder unwraps the field. `components` is the
+/// `XFormComponents` type the field's declarations go on.
+fn component_content(
+    component: impl ComponentLayout,
+    field_name: String,
+    field_type: &Type,
+    components: &TokenStream,
+    should_be_unwrapped: bool,
+) -> darling::Result<ComponentFieldContent> {
+    let mut field_structure_tokens = TokenStream::new();
+    let mut field_base_declarations_tokens = TokenStream::new();
+    component.field_tokens(
+        &mut field_structure_tokens,
+        &mut field_base_declarations_tokens,
+    );
+    let value_bounds = component
+        .value_bounds()
+        .map(|bounds| extract_type_ident(field_type).map(|ident| (ident, bounds)))
+        .transpose()?;
+
+    Ok(ComponentFieldContent {
+        field_structure_tokens,
+        field_base_declarations_tokens,
+        field_initializer_tokens: component.field_initializer_tokens(),
+        subscription_tokens: component.subscription_tokens(),
+        state_tokens: component.state_tokens(components),
+        load_tokens: component.load_tokens(),
+        read_tokens: component.read_tokens(),
+        value_bounds,
+        should_be_unwrapped: (field_name, should_be_unwrapped),
+    })
+}
+
+/// `components` is the `XFormComponents` type the field's declarations go on.
+fn generate_component_field(
+    field: &ComponentField,
+    components: &TokenStream,
+) -> darling::Result<ComponentFieldContent> {
+    let Some(field_ident) = field.ident.as_ref() else {
+        return Err(darling::Error::custom("expected a named field").with_span(&field.ty));
+    };
+    let field_name = field_ident.to_string();
+    let field_type = &field.ty;
+
+    if field.placeholder.is_some()
+        && !matches!(
+            field.component,
+            Some(Components::Input(_) | Components::Textarea(_) | Components::NumberInput)
+        )
+    {
+        return Err(darling::Error::custom(
+            "`placeholder` only applies to `input`, `textarea` and `number_input` fields",
+        )
+        .with_span(field_ident));
+    }
+
+    let Some(component_def) = field.component.as_ref() else {
+        return Ok(ComponentFieldContent {
+            field_structure_tokens: TokenStream::new(),
+            field_base_declarations_tokens: TokenStream::new(),
+            field_initializer_tokens: Some(TokenStream::new()),
+            subscription_tokens: None,
+            state_tokens: None,
+            load_tokens: None,
+            read_tokens: None,
+            value_bounds: None,
+            should_be_unwrapped: (field_name, false),
+        });
+    };
+
+    match component_def {
+        Components::Input(options) => component_content(
+            InputComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_placeholder(field.placeholder.clone()),
+            ),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Textarea(options) => component_content(
+            TextareaComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_placeholder(field.placeholder.clone()),
+            ),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Otp(options) => component_content(
+            OtpComponent(FieldInformation::new(
+                options.clone(),
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::NumberInput => component_content(
+            NumberInputComponent(
+                FieldInformation::new(
+                    NumberInputOptions,
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_placeholder(field.placeholder.clone()),
+            ),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Checkbox => component_content(
+            CheckboxComponent(FieldInformation::new(
+                CheckboxOptions,
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Switch => component_content(
+            SwitchComponent(FieldInformation::new(
+                SwitchOptions,
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Dropdown(options) => component_content(
+            DropdownComponent(FieldInformation::new(
+                options.clone(),
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::MultiSelect => component_content(
+            MultiSelectComponent(
+                FieldInformation::new(
+                    MultiSelectOptions,
+                    field_name.clone(),
+                    extract_type_ident(&extract_element_type(field_type)?)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::RadioGroup(options) => component_content(
+            RadioGroupComponent(FieldInformation::new(
+                options.clone(),
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::DatePicker => component_content(
+            DatePickerComponent(
+                FieldInformation::new(
+                    DatePickerOptions,
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::DateRangePicker(options) => component_content(
+            DateRangePickerComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::TimePicker(options) => component_content(
+            TimePickerComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::DatetimePicker(options) => component_content(
+            DatetimePickerComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::ColorPicker => component_content(
+            ColorPickerComponent(
+                FieldInformation::new(
+                    ColorPickerOptions,
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::Slider(options) => component_content(
+            SliderComponent(FieldInformation::new(
+                options.clone(),
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+        Components::Nested => component_content(
+            NestedComponent(
+                FieldInformation::new(
+                    NestedOptions,
+                    field_name.clone(),
+                    extract_type_ident(field_type)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::List(options) => component_content(
+            ListComponent(
+                FieldInformation::new(
+                    options.clone(),
+                    field_name.clone(),
+                    extract_type_ident(&extract_element_type(field_type)?)?,
+                )
+                .with_optional(is_option(field_type)),
+            ),
+            field_name,
+            field_type,
+            components,
+            false,
+        ),
+        Components::Custom(options) => component_content(
+            CustomComponent(FieldInformation::new(
+                options.clone(),
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            options.behaviour.should_be_unwrapped,
+        ),
+        Components::Inferred => component_content(
+            InferredComponent(FieldInformation::new(
+                InferredOptions,
+                field_name.clone(),
+                extract_type_ident(field_type)?,
+            )),
+            field_name,
+            field_type,
+            components,
+            true,
+        ),
+    }
+}
+
+/// Ident of a component field's type, looking through an outer `Option`.
+fn extract_type_ident(ty: &Type) -> darling::Result<Ident> {
+    match ty {
+        Type::Path(type_path) => {
+            let Some(last_segment) = type_path.path.segments.last() else {
+                return Err(darling::Error::custom("expected a type path").with_span(ty));
+            };
+
+            if last_segment.ident == "Option"
+                && let PathArguments::AngleBracketed(args) = &last_segment.arguments
+                && let Some(GenericArgument::Type(inner_type)) = args.args.first()
+            {
+                return extract_type_ident(inner_type);
+            }
+            Ok(last_segment.ident.clone())
+        },
+        Type::Tuple(type_tuple) if !type_tuple.elems.is_empty() => {
+            extract_type_ident(&type_tuple.elems[0])
+        },
+        _ => Err(darling::Error::custom(
+            "unsupported type for a component field, expected a path such as `String` or `Option<T>`",
+        )
+        .with_span(ty)),
+    }
+}
+
+/// Whether a datetime type, looking through an outer `Option`, carries a time zone: a
+/// `chrono::DateTime<Tz>` or a `jiff::Zoned`, rather than a `NaiveDateTime` or a
+/// `jiff::civil::DateTime`.
+fn is_zoned(ty: &Type) -> bool {
+    let ty = option_inner_type(ty).unwrap_or(ty);
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.path.segments.last().is_some_and(|segment| {
+        segment.ident == "Zoned"
+            || (segment.ident == "DateTime"
+                && matches!(segment.arguments, PathArguments::AngleBracketed(_)))
+    })
+}
+
+fn is_masked(component: &Components) -> bool {
+    match component {
+        Components::Input(options) => options.behaviour.masked,
+        Components::Otp(options) => options.behaviour.masked,
+        _ => false,
+    }
+}
+
+fn is_debug_path(path: &syn::Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Debug")
+}
+
+/// Derives of a value holder written by this macro, `Debug` being left to [`masked_debug`]
+/// when it has masked fields.
+fn value_holder_derives(masked_fields: &[&Ident]) -> TokenStream {
+    if masked_fields.is_empty() {
+        quote! { #[derive(Clone, Debug, Default)] }
+    } else {
+        quote! { #[derive(Clone, Default)] }
+    }
+}
+
+/// `Debug` of a value holder with masked fields, printing `***` for them.
+fn masked_debug(
+    value_holder_name: &Ident,
+    generics: &syn::Generics,
+    fields: &[(Ident, Type)],
+    masked_fields: &[&Ident],
+) -> TokenStream {
+    let mut where_clause = generics.clone().make_where_clause().clone();
+    if !generics.params.is_empty() {
+        where_clause.predicates.extend(
+            fields
+                .iter()
+                .filter(|(ident, _)| !masked_fields.contains(&ident))
+                .map(|(_, ty)| -> syn::WherePredicate {
+                    syn::parse_quote! { #ty: ::std::fmt::Debug }
+                }),
+        );
+    }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let debug_fields = fields.iter().map(|(ident, _)| {
+        let ident_str = ident.to_string();
+        if masked_fields.contains(&ident) {
+            quote! { .field(#ident_str, &"***") }
+        } else {
+            quote! { .field(#ident_str, &self.#ident) }
+        }
+    });
+    let value_holder_name_str = value_holder_name.to_string();
+
+    quote! {
+        impl #impl_generics ::std::fmt::Debug for #value_holder_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(#value_holder_name_str)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        }
+    }
+}
+
+/// Swaps the `Debug` that `unwrapped` gives the value holder for [`masked_debug`], for
+/// structs with masked fields.
+///
+/// Errors instead of leaving the holder's `Debug` as is when it can't be rewritten.
+fn redact_masked_debug(
+    model_struct: TokenStream,
+    value_holder_name: &Ident,
+    masked_fields: &[&Ident],
+) -> darling::Result<TokenStream> {
+    if masked_fields.is_empty() {
+        return Ok(model_struct);
+    }
+    let mut file = syn::parse2::<syn::File>(model_struct)?;
+
+    let is_holder = |ty: &Type| match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == *value_holder_name),
+        _ => false,
+    };
+    file.items.retain(|item| match item {
+        syn::Item::Impl(item_impl) => {
+            !(is_holder(&item_impl.self_ty)
+                && item_impl
+                    .trait_
+                    .as_ref()
+                    .is_some_and(|(_, path, _)| is_debug_path(path)))
+        },
+        _ => true,
+    });
+
+    let mut holder_fields = None;
+    let mut holder_generics = syn::Generics::default();
+    for item in &mut file.items {
+        let syn::Item::Struct(item_struct) = item else {
+            continue;
+        };
+        if item_struct.ident != *value_holder_name {
+            continue;
+        }
+        for attr in &mut item_struct.attrs {
+            if !attr.path().is_ident("derive") {
+                continue;
+            }
+            let paths =
+                attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)?;
+            let paths = paths.into_iter().filter(|path| !is_debug_path(path));
+            *attr = syn::parse_quote! { #[derive(#(#paths),*)] };
+        }
+        holder_generics = item_struct.generics.clone();
+        holder_fields = Some(
+            item_struct
+                .fields
+                .iter()
+                .filter_map(|field| Some((field.ident.clone()?, field.ty.clone())))
+                .collect::<Vec<_>>(),
+        );
+    }
+    let Some(holder_fields) = holder_fields else {
+        return Err(darling::Error::custom(format!(
+            "`{value_holder_name}` wasn't found to redact its masked fields"
+        ))
+        .with_span(value_holder_name));
+    };
+
+    let debug_impl = masked_debug(
+        value_holder_name,
+        &holder_generics,
+        &holder_fields,
+        masked_fields,
+    );
+    Ok(quote! {
+        #file
+
+        #debug_impl
+    })
+}
+
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Option")
+}
+
+/// `T` for an `Option<T>`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner_type)) = args.args.first()
+    {
+        return Some(inner_type);
+    }
+    None
+}
+
+/// A `component(nested)` or `list(item = nested)` field, whose value holder entry is the
+/// nested struct's own holder.
+struct NestedField<'a> {
+    ident: &'a Ident,
+    value_holder: Ident,
+    optional: bool,
+    list: bool,
+}
+
+impl<'a> NestedField<'a> {
+    fn from_field(field: &'a ComponentField) -> darling::Result<Option<Self>> {
+        let (Some(ident), Some(component)) = (field.ident.as_ref(), field.component.as_ref())
+        else {
+            return Ok(None);
+        };
+        let (value_type, list) = match component {
+            Components::Nested => (extract_type_ident(&field.ty)?, false),
+            Components::List(options) if options.behaviour.item == ListItem::Nested => {
+                (extract_type_ident(&extract_element_type(&field.ty)?)?, true)
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(Self {
+            ident,
+            value_holder: format_ident!("{}FormValueHolder", value_type),
+            optional: is_option(&field.ty),
+            list,
+        }))
+    }
+
+    /// Type of the field in the value holder.
+    fn value_holder_type(&self) -> Type {
+        let value_holder = &self.value_holder;
+        let value_holder: Type = if self.list {
+            syn::parse_quote! { ::std::vec::Vec<#value_holder> }
+        } else {
+            syn::parse_quote! { #value_holder }
+        };
+        if self.optional {
+            syn::parse_quote! { ::core::option::Option<#value_holder> }
+        } else {
+            value_holder
+        }
+    }
+
+    /// Converts `expr` between the field's model type and its value holder type.
+    fn convert(&self, expr: &syn::Expr) -> syn::Expr {
+        match (self.optional, self.list) {
+            (false, false) => syn::parse_quote! { ::core::convert::Into::into(#expr) },
+            (true, false) => syn::parse_quote! { (#expr).map(::core::convert::Into::into) },
+            (false, true) => syn::parse_quote! {
+                (#expr).into_iter().map(::core::convert::Into::into).collect()
+            },
+            (true, true) => syn::parse_quote! {
+                (#expr).map(|items| items.into_iter().map(::core::convert::Into::into).collect())
+            },
+        }
+    }
+}
+
+/// Wraps the nested members of the struct expressions unwrapped builds in `Into::into`.
+struct NestedConversions<'a>(&'a [NestedField<'a>]);
+
+impl VisitMut for NestedConversions<'_> {
+    fn visit_expr_struct_mut(&mut self, expr_struct: &mut syn::ExprStruct) {
+        visit_mut::visit_expr_struct_mut(self, expr_struct);
+        for field_value in &mut expr_struct.fields {
+            let syn::Member::Named(member) = &field_value.member else {
+                continue;
+            };
+            let Some(nested) = self.0.iter().find(|nested| nested.ident == member) else {
+                continue;
+            };
+            field_value.expr = nested.convert(&field_value.expr);
+            field_value.colon_token.get_or_insert_with(Default::default);
+        }
+    }
+}
+
+/// Makes the value holder keep nested structs as their own value holders.
+fn nest_value_holders(
+    model_struct: TokenStream,
+    value_holder_name: &Ident,
+    nested_fields: &[NestedField],
+) -> TokenStream {
+    if nested_fields.is_empty() {
+        return model_struct;
+    }
+    let Ok(mut file) = syn::parse2::<syn::File>(model_struct.clone()) else {
+        return model_struct;
+    };
+
+    for item in &mut file.items {
+        match item {
+            syn::Item::Struct(item_struct) if item_struct.ident == *value_holder_name => {
+                for field in &mut item_struct.fields {
+                    let Some(nested) = nested_fields
+                        .iter()
+                        .find(|nested| field.ident.as_ref() == Some(nested.ident))
+                    else {
+                        continue;
+                    };
+                    field.ty = nested.value_holder_type();
+                }
+            },
+            syn::Item::Impl(item_impl) => {
+                NestedConversions(nested_fields).visit_item_impl_mut(item_impl);
+            },
+            _ => {},
+        }
+    }
+
+    file.into_token_stream()
+}
+
+/// Item type of a `Vec<T>`/`HashSet<T>` field, looking through an outer `Option`.
+fn extract_element_type(ty: &Type) -> darling::Result<Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(last_segment) = type_path.path.segments.last()
+        && let PathArguments::AngleBracketed(args) = &last_segment.arguments
+        && let Some(GenericArgument::Type(inner_type)) = args.args.first()
+    {
+        if last_segment.ident == "Option" {
+            return extract_element_type(inner_type);
+        }
+        return Ok(inner_type.clone());
+    }
+    Err(
+        darling::Error::custom("expected a collection type such as `Vec<T>` or `HashSet<T>`")
+            .with_span(ty),
+    )
+}
+
+pub struct GpuiFormOptions {
+    pub generate_shape: bool,
+    /// Whether garde-validated structs get a `validate_fields`.
+    pub validate: bool,
+}
+
+/// What the named fields of a struct, or of an enum variant, turn into.
+struct FormComponentsContent {
+    /// `XFormFields` and `XFormComponents`.
+    tokens: TokenStream,
+    /// `FieldVariant`s of the fields, for the `GpuiFormShape`.
+    field_variants: Vec<TokenStream>,
+    should_be_unwrapped: HashMap<String, bool>,
+    has_build_fields: bool,
+    /// Methods the fields add to `XFormState`.
+    state_tokens: Vec<TokenStream>,
+    /// Bounds on type parameters the generated `load` and `read` need.
+    load_bounds: Vec<syn::WherePredicate>,
+}
+
+fn field_variant(
+    field: &ComponentField,
+    component_def: &Components,
+) -> darling::Result<TokenStream> {
+    let Some(field_ident) = field.ident.as_ref() else {
+        return Err(darling::Error::custom("expected a named field").with_span(&field.ty));
+    };
+    let field_name_str = field_ident.to_string();
+    let (is_optional, base_type) = match option_inner_type(&field.ty) {
+        Some(inner_type) => (true, inner_type),
+        None => (false, &field.ty),
+    };
+
+    let field_type_str = base_type.to_token_stream().to_string();
+    let behaviour_tokens = get_components_behaviour_tokens(component_def, base_type);
+    let element_type_tokens = if let Components::MultiSelect | Components::List(_) = component_def {
+        let element_type_str = extract_element_type(&field.ty)?
+            .to_token_stream()
+            .to_string();
+        quote! { .with_element_type(#element_type_str) }
+    } else {
+        quote! {}
+    };
+    let nested_shape_tokens = match component_def {
+        Components::Nested => {
+            let nested_shape_str = extract_type_ident(&field.ty)?.to_string();
+            quote! { .with_nested_shape(#nested_shape_str) }
+        },
+        Components::List(options) if options.behaviour.item == ListItem::Nested => {
+            let nested_shape_str =
+                extract_type_ident(&extract_element_type(&field.ty)?)?.to_string();
+            quote! { .with_nested_shape(#nested_shape_str) }
+        },
+        _ => quote! {},
+    };
+    let inferred_tokens = if let Components::Inferred = component_def {
+        quote! { .with_inferred_component() }
+    } else {
+        quote! {}
+    };
+    let label_tokens = field
+        .label
+        .as_ref()
+        .map(|label| quote! { .with_label(#label) });
+    let description_tokens = field
+        .description
+        .as_ref()
+        .map(|description| quote! { .with_description(#description) });
+    let placeholder_tokens = field
+        .placeholder
+        .as_ref()
+        .map(|placeholder| quote! { .with_placeholder(#placeholder) });
+    let validation_tokens = field.validate.as_ref().map(|rules| {
+        let some_usize = |value: Option<usize>| match value {
+            Some(value) => quote! { ::core::option::Option::Some(#value) },
+            None => quote! { ::core::option::Option::None },
+        };
+        let some_str = |value: Option<String>| match value {
+            Some(value) => quote! { ::core::option::Option::Some(#value) },
+            None => quote! { ::core::option::Option::None },
+        };
+        let required = rules.required;
+        let min_len = some_usize(rules.min_len);
+        let max_len = some_usize(rules.max_len);
+        let min = some_str(
+            rules
+                .min
+                .as_ref()
+                .map(|min| min.to_token_stream().to_string()),
+        );
+        let max = some_str(
+            rules
+                .max
+                .as_ref()
+                .map(|max| max.to_token_stream().to_string()),
+        );
+        let pattern = some_str(rules.pattern.as_ref().map(syn::LitStr::value));
+        quote! {
+            .with_validation(::gpui_form::core::registry::FieldValidation {
+                required: #required,
+                min_len: #min_len,
+                max_len: #max_len,
+                min: #min,
+                max: #max,
+                pattern: #pattern,
+            })
+        }
+    });
+    Ok(quote! {
+        ::gpui_form::core::registry::FieldVariant::new(
+            #field_name_str,
+            #field_type_str,
+            #is_optional,
+            #behaviour_tokens
+        )
+        #element_type_tokens
+        #nested_shape_tokens
+        #inferred_tokens
+        #label_tokens
+        #description_tokens
+        #placeholder_tokens
+        #validation_tokens
+    })
+}
+
+/// `PhantomData` over every generic parameter, which the generated structs may not otherwise use.
+fn generics_marker(generics: &syn::Generics) -> Option<TokenStream> {
+    if generics.params.is_empty() {
+        return None;
+    }
+    let lifetimes = generics.lifetimes().map(|param| &param.lifetime);
+    let type_params = generics.type_params().map(|param| &param.ident);
+    Some(quote! {
+        ::core::marker::PhantomData<(#(&#lifetimes (),)* fn() -> (#(#type_params,)*))>
+    })
+}
+
+fn field_variants(fields: &[ComponentField]) -> darling::Result<Vec<TokenStream>> {
+    let mut errors = darling::Error::accumulator();
+    let field_variants = fields
+        .iter()
+        .filter(|field| !field.skip())
+        .filter_map(|field| errors.handle(field_variant(field, field.component.as_ref()?)))
+        .collect();
+    errors.finish_with(field_variants)
+}
+
+fn form_components(
+    form_name: &Ident,
+    generics: &syn::Generics,
+    fields: &[ComponentField],
+    subscribe: bool,
+) -> darling::Result<FormComponentsContent> {
+    use __crate_paths::gpui::{App, AppContext, Context, Subscription, Window};
+
+    let components_holder_name = format_ident!("{}FormFields", form_name);
+    let components_base_declarations_name = format_ident!("{}FormComponents", form_name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let marker = generics_marker(generics);
+    let components = quote! { <#components_base_declarations_name #ty_generics> };
+
+    let mut errors = darling::Error::accumulator();
+    let component_field_pairs: Vec<ComponentFieldContent> = fields
+        .iter()
+        .filter(|field| !field.skip())
+        .filter_map(|field| errors.handle(generate_component_field(field, &components)))
+        .collect();
+    let field_variants = errors.handle(field_variants(fields)).unwrap_or_default();
+    errors.finish()?;
+
+    let (
+        field_structure_tokens,
+        field_base_declarations_tokens,
+        field_initializer_tokens,
+        subscription_tokens,
+        state_tokens,
+        load_tokens,
+        read_tokens,
+        value_bounds,
+        should_be_unwrapped,
+    ): (
+        Vec<TokenStream>,
+        Vec<TokenStream>,
+        Vec<Option<TokenStream>>,
+        Vec<Option<TokenStream>>,
+        Vec<Option<TokenStream>>,
+        Vec<Option<TokenStream>>,
+        Vec<Option<TokenStream>>,
+        Vec<Option<(Ident, TokenStream)>>,
+        HashMap<String, bool>,
+    ) = component_field_pairs
+        .into_iter()
+        .map(|content| {
+            (
+                content.field_structure_tokens,
+                content.field_base_declarations_tokens,
+                content.field_initializer_tokens,
+                content.subscription_tokens,
+                content.state_tokens,
+                content.load_tokens,
+                content.read_tokens,
+                content.value_bounds,
+                content.should_be_unwrapped,
+            )
+        })
+        .multiunzip();
+
+    let build_fields = field_initializer_tokens
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .map(|field_initializer_tokens| {
+            let marker_initializer = marker
+                .as_ref()
+                .map(|_| quote! { _marker: ::core::marker::PhantomData, });
+            quote! {
+                #[allow(unused_imports, unused_variables)]
+                pub fn build_fields(
+                    window: &mut #Window,
+                    cx: &mut #App,
+                ) -> #components_holder_name #ty_generics {
+                    use #AppContext as _;
+                    #components_holder_name {
+                        #(#field_initializer_tokens)*
+                        #marker_initializer
+                    }
+                }
+            }
+        });
+    let has_build_fields = build_fields.is_some();
+
+    let value_holder_name = format_ident!("{}FormValueHolder", form_name);
+    let subscription_tokens = subscription_tokens.into_iter().flatten();
+    let subscribe = subscribe.then(|| {
+        quote! {
+            impl #impl_generics #components_holder_name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                pub fn subscribe<S: 'static>(
+                    &self,
+                    window: &mut #Window,
+                    cx: &mut #Context<S>,
+                    data: impl Fn(&mut S) -> &mut #value_holder_name #ty_generics + Copy + 'static,
+                ) -> ::std::vec::Vec<#Subscription> {
+                    let mut subscriptions = ::std::vec::Vec::new();
+                    #(#subscription_tokens)*
+                    subscriptions
+                }
+            }
+        }
+    });
+
+    // fields typed by a type parameter only get `load` and `read` if it allows them
+    let type_params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let load_bounds: Vec<syn::WherePredicate> = value_bounds
+        .into_iter()
+        .flatten()
+        .filter(|(ident, _)| type_params.contains(&ident))
+        .map(|(ident, bounds)| syn::parse_quote! { #ident: #bounds })
+        .collect();
+    let mut load_generics = generics.clone();
+    load_generics
+        .make_where_clause()
+        .predicates
+        .extend(load_bounds.iter().cloned());
+    let (_, _, load_where_clause) = load_generics.split_for_impl();
+    let load_tokens = load_tokens.into_iter().flatten();
+    let read_tokens = read_tokens.into_iter().flatten();
+    let load = quote! {
+        impl #impl_generics #components_holder_name #ty_generics #load_where_clause {
+            /// Sets the states of the fields to the values of `holder`.
+            #[allow(unused_variables)]
+            pub fn load(
+                &mut self,
+                holder: &#value_holder_name #ty_generics,
+                window: &mut #Window,
+                cx: &mut #App,
+            ) {
+                #({ #load_tokens })*
+            }
+
+            /// Writes the values of the states of the fields back to `holder`.
+            #[allow(unused_variables)]
+            pub fn read(&self, holder: &mut #value_holder_name #ty_generics, cx: &#App) {
+                #({ #read_tokens })*
+            }
+        }
+    };
+
+    let struct_where_clause = &generics.where_clause;
+    let (fields_marker, components_struct) = match &marker {
+        Some(marker) => (
+            quote! { pub _marker: #marker, },
+            quote! {
+                pub struct #components_base_declarations_name #generics #struct_where_clause {
+                    _marker: #marker,
+                }
+            },
+        ),
+        None => (
+            quote! {},
+            quote! { pub struct #components_base_declarations_name; },
+        ),
+    };
+
+    let tokens = quote! {
+        pub struct #components_holder_name #generics #struct_where_clause {
+            #(#field_structure_tokens)*
+            #fields_marker
+        }
+
+        #components_struct
+
+        impl #impl_generics #components_base_declarations_name #ty_generics #where_clause {
+          #(#field_base_declarations_tokens)*
+
+          #build_fields
+        }
+
+        #load
+
+        #subscribe
+    };
+
+    Ok(FormComponentsContent {
+        tokens,
+        field_variants,
+        should_be_unwrapped,
+        has_build_fields,
+        state_tokens: state_tokens.into_iter().flatten().collect(),
+        load_bounds,
+    })
+}
+
+/// The `GpuiFormShape` describing `form_name`, a constant expression.
+fn shape_expr(
+    form_name: &Ident,
+    field_variants: &[TokenStream],
+    variant_of: Option<&Ident>,
+    generic_args: Option<&str>,
+) -> TokenStream {
+    let variant_of = variant_of.map(|enum_name| {
+        let enum_name_str = enum_name.to_string();
+        quote! { .variant_of(#enum_name_str) }
+    });
+    let generic_args = generic_args.map(|generic_args| {
+        quote! { .with_generic_args(#generic_args) }
+    });
+    quote! {
+        ::gpui_form::core::registry::GpuiFormShape::new(
+            stringify!(#form_name),
+            &[
+                #(#field_variants),*
+            ]
+        )
+        #variant_of
+        #generic_args
+    }
+}
+
+fn shape_registration(options: &GpuiFormOptions, shape: &TokenStream) -> TokenStream {
+    if !options.generate_shape {
+        return quote! {};
+    }
+    quote! {
+        ::gpui_form::core::registry::inventory::submit! {
+            #shape
+        }
+    }
+}
+
+/// `form_type` with the last segment of its path suffixed, e.g. `Settings<u32>` to
+/// `SettingsFormFields<u32>`.
+fn suffixed_type(form_type: &Type, suffix: &str) -> Type {
+    let mut suffixed = form_type.clone();
+    if let Type::Path(type_path) = &mut suffixed
+        && let Some(segment) = type_path.path.segments.last_mut()
+    {
+        segment.ident = format_ident!("{}{}", segment.ident, suffix);
+    }
+    suffixed
+}
+
+/// `impl gpui_form::Form` for `form_type`, a derived type without type parameters.
+fn form_impl(form_type: &Type, shape: &TokenStream) -> TokenStream {
+    use __crate_paths::gpui::{App, Window};
+
+    let value_holder_type = suffixed_type(form_type, "FormValueHolder");
+    let components_holder_type = suffixed_type(form_type, "FormFields");
+    let components_base_declarations_type = suffixed_type(form_type, "FormComponents");
+    quote! {
+        impl ::gpui_form::Form for #form_type {
+            type ValueHolder = #value_holder_type;
+            type Fields = #components_holder_type;
+
+            fn build_fields(
+                window: &mut #Window,
+                cx: &mut #App,
+            ) -> Self::Fields {
+                <#components_base_declarations_type>::build_fields(window, cx)
+            }
+
+            fn shape() -> &'static ::gpui_form::core::registry::GpuiFormShape {
+                static SHAPE: ::gpui_form::core::registry::GpuiFormShape = #shape;
+                &SHAPE
+            }
+        }
+    }
+}
+
+/// Replaces a generic struct's type parameters with the arguments of a registered instantiation.
+struct GenericArgsSubstitution(HashMap<Ident, Type>);
+
+impl VisitMut for GenericArgsSubstitution {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(type_path) = ty
+            && type_path.qself.is_none()
+            && let Some(ident) = type_path.path.get_ident()
+            && let Some(arg) = self.0.get(ident)
+        {
+            *ty = arg.clone();
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// `FieldVariant`s and generic arguments of `registered`, an instantiation of the generic
+/// struct `struct_name`.
+fn registered_field_variants(
+    struct_name: &Ident,
+    generics: &syn::Generics,
+    fields: &[ComponentField],
+    registered: &Type,
+) -> darling::Result<(Vec<TokenStream>, String)> {
+    let segment = match registered {
+        Type::Path(type_path) => type_path.path.segments.last(),
+        _ => None,
+    }
+    .filter(|segment| segment.ident == *struct_name)
+    .ok_or_else(|| {
+        darling::Error::custom(format!("expected an instantiation of `{struct_name}`"))
+            .with_span(registered)
+    })?;
+
+    let args: Vec<&Type> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let type_params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    if args.len() != type_params.len() {
+        return Err(darling::Error::custom(format!(
+            "expected {} type argument(s) for `{struct_name}`",
+            type_params.len()
+        ))
+        .with_span(registered));
+    }
+
+    let mut substitution = GenericArgsSubstitution(
+        type_params
+            .into_iter()
+            .cloned()
+            .zip(args.iter().map(|arg| (*arg).clone()))
+            .collect(),
+    );
+    let fields: Vec<ComponentField> = fields
+        .iter()
+        .cloned()
+        .map(|mut field| {
+            substitution.visit_type_mut(&mut field.ty);
+            field
+        })
+        .collect();
+    let generic_args = args
+        .iter()
+        .map(|arg| arg.to_token_stream().to_string())
+        .join(", ");
+
+    Ok((field_variants(&fields)?, generic_args))
+}
+
+/// Shape registration and `Form` impl of a struct, one each per registered instantiation if it
+/// is generic.
+fn struct_shapes(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    field_variants: &[TokenStream],
+    options: &GpuiFormOptions,
+    has_build_fields: bool,
+    validated: bool,
+    checked: bool,
+) -> darling::Result<TokenStream> {
+    let struct_name = &parsed.ident;
+    let shape_tokens = |shape: TokenStream, form_type: Type| {
+        let shape = if validated {
+            quote! { #shape.validated() }
+        } else {
+            shape
+        };
+        let shape = if checked {
+            quote! { #shape.checked() }
+        } else {
+            shape
+        };
+        let shape = if parsed.track_changes {
+            quote! { #shape.tracked() }
+        } else {
+            shape
+        };
+        let registration = shape_registration(options, &shape);
+        let form_impl = has_build_fields.then(|| form_impl(&form_type, &shape));
+        quote! {
+            #registration
+            #form_impl
+        }
+    };
+    if parsed.generics.params.is_empty() {
+        if let Some(registered) = parsed.register.0.first() {
+            return Err(
+                darling::Error::custom("`register` only applies to generic structs")
+                    .with_span(registered),
+            );
+        }
+        Ok(shape_tokens(
+            shape_expr(struct_name, field_variants, None, None),
+            syn::parse_quote! { #struct_name },
+        ))
+    } else {
+        let mut errors = darling::Error::accumulator();
+        let shapes: Vec<TokenStream> = parsed
+            .register
+            .0
+            .iter()
+            .filter_map(|registered| {
+                errors
+                    .handle(registered_field_variants(
+                        struct_name,
+                        &parsed.generics,
+                        fields,
+                        registered,
+                    ))
+                    .map(|variants| (registered, variants))
+            })
+            .map(|(registered, (field_variants, generic_args))| {
+                shape_tokens(
+                    shape_expr(struct_name, &field_variants, None, Some(&generic_args)),
+                    registered.clone(),
+                )
+            })
+            .collect();
+        errors.finish()?;
+        Ok(quote! { #(#shapes)* })
+    }
+}
+
+/// `XFormValueHolder::changed_fields` and `is_dirty`, comparing the fields of `fields` once
+/// the holder and the original struct are both converted through the holder, along with
+/// `XFormState::changed_fields` and `is_dirty` comparing to its `original` for `entity`
+/// structs, for `track_changes` structs. `members` reach each of `fields` in the struct.
+fn changed_fields(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    members: &[syn::Member],
+) -> Option<TokenStream> {
+    if !parsed.track_changes {
+        return None;
+    }
+
+    let struct_name = &parsed.ident;
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+    let state_name = format_ident!("{}FormState", struct_name);
+    let (field_names, compared): (Vec<String>, Vec<(&syn::Member, &Type)>) = fields
+        .iter()
+        .zip(members)
+        .filter(|(field, _)| !field.skip() && field.component.is_some())
+        .filter_map(|(field, member)| {
+            Some((field.ident.as_ref()?.to_string(), (member, &field.ty)))
+        })
+        .unzip();
+    let compared_members = compared.iter().map(|(member, _)| member);
+
+    let generics = &parsed.generics;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let mut bounded_generics = generics.clone();
+    if !generics.params.is_empty() {
+        let predicates = &mut bounded_generics.make_where_clause().predicates;
+        predicates
+            .push(syn::parse_quote! { #value_holder_name #ty_generics: ::core::clone::Clone });
+        predicates.push(syn::parse_quote! { #struct_name #ty_generics: ::core::clone::Clone });
+        predicates.extend(compared.iter().map(|(_, ty)| -> syn::WherePredicate {
+            syn::parse_quote! { #ty: ::core::cmp::PartialEq }
+        }));
+    }
+    let (_, _, where_clause) = bounded_generics.split_for_impl();
+
+    let state_impl = parsed.entity.then(|| {
+        quote! {
+            impl #impl_generics #state_name #ty_generics #where_clause {
+                /// Names of the fields whose value differs from theirs in `original`.
+                pub fn changed_fields(&self) -> ::std::vec::Vec<&'static str> {
+                    self.current.changed_fields(&self.original)
+                }
+
+                /// Whether a field differs from its value in `original`.
+                pub fn is_dirty(&self) -> bool {
+                    self.current.is_dirty(&self.original)
+                }
+            }
+        }
+    });
+
+    Some(quote! {
+        impl #impl_generics #value_holder_name #ty_generics #where_clause {
+            /// Names of the fields whose value differs from theirs in `original`.
+            pub fn changed_fields(
+                &self,
+                original: &#struct_name #ty_generics,
+            ) -> ::std::vec::Vec<&'static str> {
+                let current: #struct_name #ty_generics = ::core::clone::Clone::clone(self).into();
+                // the round trip through the holder can turn a `None` into a `Some`, so the
+                // original takes it too
+                let original: #struct_name #ty_generics =
+                    #value_holder_name::from(::core::clone::Clone::clone(original)).into();
+                let mut changed = ::std::vec::Vec::new();
+                #(
+                    if current.#compared_members != original.#compared_members {
+                        changed.push(#field_names);
+                    }
+                )*
+                changed
+            }
+
+            /// Whether a field differs from its value in `original`.
+            pub fn is_dirty(&self, original: &#struct_name #ty_generics) -> bool {
+                !self.changed_fields(original).is_empty()
+            }
+        }
+
+        #state_impl
+    })
+}
+
+fn struct_form(
+    derive_input: &DeriveInput,
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    options: &GpuiFormOptions,
+) -> darling::Result<TokenStream> {
+    let struct_name = &parsed.ident;
+    let FormComponentsContent {
+        tokens: form_components_tokens,
+        field_variants,
+        should_be_unwrapped,
+        has_build_fields,
+        state_tokens,
+        load_bounds,
+    } = form_components(struct_name, &parsed.generics, fields, parsed.entity)?;
+
+    let garde_names: Vec<String> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| ident.to_string())
+        .collect();
+    let validate_fields = validate_fields(parsed, fields, &garde_names, options);
+    let validate_rules = validate_rules(parsed, fields, &should_be_unwrapped)?;
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .filter_map(|field| field.ident.clone())
+        .map(syn::Member::Named)
+        .collect();
+    let changed_fields = changed_fields(parsed, fields, &members);
+    let shape_impl = struct_shapes(
+        parsed,
+        fields,
+        &field_variants,
+        options,
+        has_build_fields,
+        validate_fields.is_some(),
+        validate_rules.is_some(),
+    )?;
+    let form_state = form_state(
+        parsed,
+        fields,
+        has_build_fields,
+        &state_tokens,
+        &load_bounds,
+    )?;
+
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+
+    let masked_fields: Vec<&Ident> = fields
+        .iter()
+        .filter(|field| field.component.as_ref().is_some_and(is_masked))
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    let nested_fields: Vec<NestedField> = fields
+        .iter()
+        .filter(|field| !field.skip())
+        .filter_map(|field| NestedField::from_field(field).transpose())
+        .collect::<darling::Result<_>>()?;
+
+    let model_options = unwrapped_core::Opts::builder()
+        .suffix(format_ident!("FormValueHolder"))
+        .build();
+
+    let macro_options =
+        unwrapped_core::ProcUsageOpts::new(should_be_unwrapped, Some(format_ident!("gpui_form")));
+
+    let model_struct = unwrapped_core::unwrapped(derive_input, Some(model_options), macro_options);
+    let model_struct = nest_value_holders(model_struct, &value_holder_name, &nested_fields);
+    let model_struct = redact_masked_debug(model_struct, &value_holder_name, &masked_fields)?;
+
+    Ok(quote! {
+        #model_struct
+
+        #form_components_tokens
+
+        #form_state
+
+        #validate_fields
+
+        #validate_rules
+
+        #changed_fields
+
+        #shape_impl
+    })
+}
+
+/// `XFormState`, the entity `#[gpui_form(entity)]` generates to hold a struct or enum being
+/// edited along with its fields, kept in sync with its value holder. `state_tokens` are the
+/// methods its fields add to it, and `load_bounds` what loading them into the fields needs.
+fn form_state(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    has_build_fields: bool,
+    state_tokens: &[TokenStream],
+    load_bounds: &[syn::WherePredicate],
+) -> darling::Result<TokenStream> {
+    use __crate_paths::gpui::{Context, Subscription, Window};
+    use __crate_paths::gpui_component::input::InputEvent;
+
+    let async_validations = async_validations(fields)?;
+    if !parsed.entity {
+        if let Some(async_validation) = async_validations.first() {
+            return Err(darling::Error::custom(
+                "`validate_async` needs `entity`, whose form state runs it",
+            )
+            .with_span(async_validation.field_ident));
+        }
+        return Ok(quote! {});
+    }
+    if !has_build_fields {
+        return Err(darling::Error::custom(
+            "`entity` needs `build_fields`, which `partial` dropdowns and `custom` components prevent",
+        )
+        .with_span(&parsed.ident));
+    }
+
+    let struct_name = &parsed.ident;
+    let state_name = format_ident!("{}FormState", struct_name);
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+    let components_holder_name = format_ident!("{}FormFields", struct_name);
+    let components_base_declarations_name = format_ident!("{}FormComponents", struct_name);
+
+    // entities are `'static`, which generic structs have to be told
+    let generics = &parsed.generics;
+    let mut bounded_generics = generics.clone();
+    if !generics.params.is_empty() {
+        bounded_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { Self: 'static });
+    }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let (_, _, where_clause) = bounded_generics.split_for_impl();
+    let struct_where_clause = &generics.where_clause;
+    // only `new` loads, so the rest of the state doesn't need what loading does
+    let load_where_clause = (!load_bounds.is_empty()).then(|| quote! { where #(#load_bounds),* });
+
+    let async_validation_name = format_ident!("{}FormAsyncValidation", struct_name);
+    let async_fields: Vec<&Ident> = async_validations
+        .iter()
+        .map(|async_validation| async_validation.field_ident)
+        .collect();
+    // the subscriptions of `subscribe` come first, so `current` has the changed value
+    let async_subscriptions: Vec<TokenStream> = async_validations
+        .iter()
+        .map(|async_validation| {
+            let AsyncValidationField {
+                field_ident,
+                state_ident,
+                validator,
+                debounce,
+            } = async_validation;
+            quote! {
+                _subscriptions.push(cx.subscribe_in(
+                    &fields.#state_ident,
+                    window,
+                    |this: &mut Self, _state, event: &#InputEvent, _window, cx| {
+                        if let #InputEvent::Change = event {
+                            let value = ::core::clone::Clone::clone(&this.current.#field_ident);
+                            this.async_validation.#field_ident.run(
+                                cx,
+                                #debounce,
+                                value,
+                                #validator,
+                                |this| &mut this.async_validation.#field_ident,
+                            );
+                        }
+                    },
+                ));
+            }
+        })
+        .collect();
+    let (
+        async_validation_struct,
+        async_validation_member,
+        async_validation_initializer,
+        subscriptions_binding,
+    ) = if async_validations.is_empty() {
+        (
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! { let _subscriptions },
+        )
+    } else {
+        (
+            quote! {
+                /// State of the `validate_async` validators of the fields of an `XFormState`.
+                #[derive(Default)]
+                pub struct #async_validation_name {
+                    #(pub #async_fields: ::gpui_form::AsyncValidation,)*
+                }
+
+                impl #async_validation_name {
+                    /// Whether a validator is waiting out its debounce or running.
+                    pub fn is_validating(&self) -> bool {
+                        #(self.#async_fields.is_validating())||*
+                    }
+
+                    /// Whether the last run of a validator failed.
+                    pub fn has_errors(&self) -> bool {
+                        #(self.#async_fields.error().is_some())||*
+                    }
+                }
+            },
+            quote! { pub async_validation: #async_validation_name, },
+            quote! { async_validation: ::core::default::Default::default(), },
+            quote! { let mut _subscriptions },
+        )
+    };
+
+    Ok(quote! {
+        #async_validation_struct
+
+        pub struct #state_name #generics #struct_where_clause {
+            pub original: #struct_name #ty_generics,
+            pub current: #value_holder_name #ty_generics,
+            pub fields: #components_holder_name #ty_generics,
+            #async_validation_member
+            _subscriptions: ::std::vec::Vec<#Subscription>,
+        }
+
+        impl #impl_generics #state_name #ty_generics #where_clause {
+            pub fn new(
+                window: &mut #Window,
+                cx: &mut #Context<Self>,
+                original: #struct_name #ty_generics,
+            ) -> Self
+            #load_where_clause
+            {
+                let mut fields = <#components_base_declarations_name #ty_generics>::build_fields(window, cx);
+                let current: #value_holder_name #ty_generics = ::core::clone::Clone::clone(&original).into();
+                fields.load(&current, window, cx);
+                #subscriptions_binding = fields.subscribe(window, cx, |this| &mut this.current);
+                #(#async_subscriptions)*
+                Self {
+                    current,
+                    original,
+                    fields,
+                    #async_validation_initializer
+                    _subscriptions,
+                }
+            }
+
+            /// Subscribes to the fields again, for after rows are pushed, removed or moved in
+            /// a list through `XFormComponents` rather than the methods of the state.
+            pub fn resubscribe(&mut self, window: &mut #Window, cx: &mut #Context<Self>) {
+                let fields = &self.fields;
+                #subscriptions_binding = fields.subscribe(window, cx, |this| &mut this.current);
+                #(#async_subscriptions)*
+                self._subscriptions = _subscriptions;
+            }
+
+            #(#state_tokens)*
+        }
+    })
+}
+
+/// Value holder for fields unwrapped can't take, an enum variant's or a tuple struct's,
+/// unwrapped and nested the way a struct's value holder is.
+struct ValueHolder {
+    tokens: TokenStream,
+    field_idents: Vec<Ident>,
+    /// Values of the value holder's fields, out of bindings of the original fields.
+    into_holder: Vec<TokenStream>,
+    /// Values of the original fields, out of bindings of the value holder's fields.
+    from_holder: Vec<TokenStream>,
+}
+
+impl ValueHolder {
+    /// `ident: value` initializers, in shorthand where the value is the binding itself.
+    fn initializers<'a>(
+        &'a self,
+        values: &'a [TokenStream],
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        self.field_idents.iter().zip(values).map(|(ident, value)| {
+            if *ident == value.to_string() {
+                quote! { #ident }
+            } else {
+                quote! { #ident: #value }
+            }
+        })
+    }
+}
+
+fn value_holder(
+    value_holder_name: &Ident,
+    generics: &syn::Generics,
+    fields: &[ComponentField],
+    should_be_unwrapped: &HashMap<String, bool>,
+) -> darling::Result<ValueHolder> {
+    let mut field_idents = Vec::new();
+    let mut field_types: Vec<Type> = Vec::new();
+    let mut into_holder = Vec::new();
+    let mut from_holder = Vec::new();
+
+    for field in fields {
+        let Some(ident) = field.ident.clone() else {
+            continue;
+        };
+        let nested = if field.skip() {
+            None
+        } else {
+            NestedField::from_field(field)?
+        };
+        let unwrapped_type = should_be_unwrapped
+            .get(&ident.to_string())
+            .is_some_and(|unwrap| *unwrap)
+            .then(|| option_inner_type(&field.ty))
+            .flatten();
+        if let Some(nested) = nested {
+            field_types.push(nested.value_holder_type());
+            let conversion = nested.convert(&syn::parse_quote! { #ident });
+            into_holder.push(conversion.to_token_stream());
+            from_holder.push(conversion.to_token_stream());
+        } else if let Some(inner_type) = unwrapped_type {
+            field_types.push(inner_type.clone());
+            into_holder.push(quote! { #ident.unwrap_or_default() });
+            from_holder.push(quote! { ::core::option::Option::Some(#ident) });
+        } else {
+            field_types.push(field.ty.clone());
+            into_holder.push(quote! { #ident });
+            from_holder.push(quote! { #ident });
+        }
+        field_idents.push(ident);
+    }
+
+    let masked_fields: Vec<&Ident> = fields
+        .iter()
+        .filter(|field| field.component.as_ref().is_some_and(is_masked))
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    let derives = value_holder_derives(&masked_fields);
+    let debug_impl = (!masked_fields.is_empty()).then(|| {
+        let holder_fields: Vec<(Ident, Type)> = field_idents
+            .iter()
+            .cloned()
+            .zip(field_types.iter().cloned())
+            .collect();
+        masked_debug(value_holder_name, generics, &holder_fields, &masked_fields)
+    });
+    let where_clause = &generics.where_clause;
+    let tokens = quote! {
+        #derives
+        pub struct #value_holder_name #generics #where_clause {
+            #(pub #field_idents: #field_types,)*
+        }
+
+        #debug_impl
+    };
+
+    Ok(ValueHolder {
+        tokens,
+        field_idents,
+        into_holder,
+        from_holder,
+    })
+}
+
+pub fn from(input: proc_macro::TokenStream, options: GpuiFormOptions) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    let parsed = match ComponentStruct::from_derive_input(&derive_input) {
+        Ok(parsed) => parsed,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    if parsed.data.is_enum() {
+        if parsed.track_changes {
+            return darling::Error::custom("`track_changes` is not supported on enums")
+                .with_span(&parsed.ident)
+                .write_errors()
+                .into();
+        }
+        if let Some(validate_with) = &parsed.validate_with {
+            return darling::Error::custom("`validate_with` is not supported on enums")
+                .with_span(validate_with)
+                .write_errors()
+                .into();
+        }
+    }
+    if parsed.track_changes && parsed.transparent {
+        return darling::Error::custom(
+            "`track_changes` only applies to structs that aren't `transparent`",
+        )
+        .with_span(&parsed.ident)
+        .write_errors()
+        .into();
+    }
+    if let Some(validate_with) = &parsed.validate_with
+        && parsed.transparent
+    {
+        return darling::Error::custom(
+            "`validate_with` only applies to structs that aren't `transparent`",
+        )
+        .with_span(validate_with)
+        .write_errors()
+        .into();
+    }
+
+    let expanded = match &parsed.data {
+        ast::Data::Struct(_) if parsed.transparent && parsed.entity => Err(darling::Error::custom(
+            "a `transparent` newtype has no form of its own to be an `entity`",
+        )
+        .with_span(&parsed.ident)),
+        ast::Data::Struct(fields) if parsed.transparent => {
+            transparent_form(&parsed, &fields.fields)
+        },
+        ast::Data::Struct(fields) if fields.is_tuple() => {
+            tuple_struct_form(&parsed, &fields.fields, &options)
+        },
+        ast::Data::Struct(fields) => struct_form(&derive_input, &parsed, &fields.fields, &options),
+        ast::Data::Enum(_) if !parsed.generics.params.is_empty() => Err(darling::Error::custom(
+            "GpuiForm does not support generic enums",
+        )
+        .with_span(&parsed.generics)),
+        ast::Data::Enum(variants) => enum_form(&parsed, variants, &options),
+    };
+
+    match expanded {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.write_errors().into(),
+    }
+}
@@ -0,0 +1,168 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Type;
+
+use super::validation::{validate_fields, validate_rules};
+use super::{
+    ComponentField, ComponentStruct, FormComponentsContent, GpuiFormOptions, changed_fields,
+    form_components, form_state, struct_shapes, value_holder,
+};
+
+/// Tuple struct fields, named `field_0`, `field_1`, … in everything generated for them.
+fn named_tuple_fields(fields: &[ComponentField]) -> Vec<ComponentField> {
+    fields
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, mut field)| {
+            field.ident = Some(format_ident!("field_{}", index));
+            field
+        })
+        .collect()
+}
+
+pub(super) fn tuple_struct_form(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    options: &GpuiFormOptions,
+) -> darling::Result<TokenStream> {
+    let struct_name = &parsed.ident;
+    let fields = named_tuple_fields(fields);
+    let FormComponentsContent {
+        tokens: form_components_tokens,
+        field_variants,
+        should_be_unwrapped,
+        has_build_fields,
+        state_tokens,
+        load_bounds,
+    } = form_components(struct_name, &parsed.generics, &fields, parsed.entity)?;
+
+    // garde names tuple struct fields by their index
+    let garde_names: Vec<String> = (0..fields.len()).map(|index| index.to_string()).collect();
+    let validate_fields = validate_fields(parsed, &fields, &garde_names, options);
+    let validate_rules = validate_rules(parsed, &fields, &should_be_unwrapped)?;
+    let members: Vec<syn::Member> = (0..fields.len()).map(syn::Member::from).collect();
+    let changed_fields = changed_fields(parsed, &fields, &members);
+    let shape_impl = struct_shapes(
+        parsed,
+        &fields,
+        &field_variants,
+        options,
+        has_build_fields,
+        validate_fields.is_some(),
+        validate_rules.is_some(),
+    )?;
+    let form_state = form_state(
+        parsed,
+        &fields,
+        has_build_fields,
+        &state_tokens,
+        &load_bounds,
+    )?;
+
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+    let value_holder = value_holder(
+        &value_holder_name,
+        &parsed.generics,
+        &fields,
+        &should_be_unwrapped,
+    )?;
+    let value_holder_tokens = &value_holder.tokens;
+    let field_idents = &value_holder.field_idents;
+    let into_holder = value_holder.initializers(&value_holder.into_holder);
+    let from_holder = &value_holder.from_holder;
+    let (impl_generics, ty_generics, where_clause) = parsed.generics.split_for_impl();
+
+    Ok(quote! {
+        #value_holder_tokens
+
+        impl #impl_generics ::core::convert::From<#struct_name #ty_generics>
+            for #value_holder_name #ty_generics #where_clause
+        {
+            fn from(from: #struct_name #ty_generics) -> Self {
+                let #struct_name(#(#field_idents),*) = from;
+                Self {
+                    #(#into_holder,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#value_holder_name #ty_generics>
+            for #struct_name #ty_generics #where_clause
+        {
+            fn from(from: #value_holder_name #ty_generics) -> Self {
+                let #value_holder_name { #(#field_idents),* } = from;
+                #struct_name(#(#from_holder),*)
+            }
+        }
+
+        #form_components_tokens
+
+        #form_state
+
+        #validate_fields
+
+        #validate_rules
+
+        #changed_fields
+
+        #shape_impl
+    })
+}
+
+/// A `#[gpui_form(transparent)]` newtype converts to and from the type it wraps, so a field of
+/// the newtype works with the component of the wrapped type.
+pub(super) fn transparent_form(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+) -> darling::Result<TokenStream> {
+    let struct_name = &parsed.ident;
+    let [field] = fields else {
+        return Err(
+            darling::Error::custom("`transparent` needs a struct with exactly one field")
+                .with_span(struct_name),
+        );
+    };
+    if field.component.is_some() {
+        return Err(darling::Error::custom(
+            "the component of a `transparent` newtype goes on the fields using it",
+        )
+        .with_span(&field.ty));
+    }
+
+    let inner_type = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = parsed.generics.split_for_impl();
+    let (wrap, unwrap) = match &field.ident {
+        Some(ident) => (quote! { Self { #ident: from } }, quote! { from.#ident }),
+        None => (quote! { Self(from) }, quote! { from.0 }),
+    };
+
+    // `impl<T> From<Newtype<T>> for T` would break the orphan rules.
+    let wraps_type_param = parsed
+        .generics
+        .type_params()
+        .any(|param| matches!(inner_type, Type::Path(type_path) if type_path.path.is_ident(&param.ident)));
+    let into_inner = (!wraps_type_param).then(|| {
+        quote! {
+            impl #impl_generics ::core::convert::From<#struct_name #ty_generics>
+                for #inner_type #where_clause
+            {
+                fn from(from: #struct_name #ty_generics) -> Self {
+                    #unwrap
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#inner_type>
+            for #struct_name #ty_generics #where_clause
+        {
+            fn from(from: #inner_type) -> Self {
+                #wrap
+            }
+        }
+
+        #into_inner
+    })
+}
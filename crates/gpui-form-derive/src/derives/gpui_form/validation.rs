@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+
+use gpui_form_core::components::{Components, ComponentsDiscriminants, ValidationRules};
+use gpui_form_core::names::ComponentFieldName;
+use proc_macro2::TokenStream;
+use quote::{ToTokens as _, format_ident, quote};
+use syn::{Ident, Type};
+
+use super::{ComponentField, ComponentStruct, GpuiFormOptions, is_option, option_inner_type};
+
+/// `XFormValueHolder::validate_fields`, for structs with `#[garde(...)]` attributes when the
+/// `validate` feature is enabled. `garde_names` are how garde names each of `fields` in its
+/// paths.
+pub(super) fn validate_fields(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    garde_names: &[String],
+    options: &GpuiFormOptions,
+) -> Option<TokenStream> {
+    let garde_validated =
+        !parsed.attrs.is_empty() || fields.iter().any(|field| !field.attrs.is_empty());
+    if !options.validate || !garde_validated {
+        return None;
+    }
+
+    let struct_name = &parsed.ident;
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+    let field_names: Vec<String> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| ident.to_string())
+        .collect();
+
+    let generics = &parsed.generics;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let mut bounded_generics = generics.clone();
+    if !generics.params.is_empty() {
+        let predicates = &mut bounded_generics.make_where_clause().predicates;
+        predicates.push(syn::parse_quote! { Self: ::core::clone::Clone });
+        predicates.push(syn::parse_quote! {
+            #struct_name #ty_generics: ::gpui_form::garde::Validate
+        });
+        predicates.push(syn::parse_quote! {
+            <#struct_name #ty_generics as ::gpui_form::garde::Validate>::Context:
+                ::core::default::Default
+        });
+    }
+    let (_, _, where_clause) = bounded_generics.split_for_impl();
+
+    Some(quote! {
+        impl #impl_generics #value_holder_name #ty_generics #where_clause {
+            /// Errors of the fields by field name, from validating the struct with garde.
+            /// Errors of a nested value are prefixed with their path, and those not about a
+            /// field are under an empty name.
+            pub fn validate_fields(
+                &self,
+            ) -> ::std::collections::HashMap<&'static str, ::std::vec::Vec<::std::string::String>> {
+                const FIELDS: &[(&str, &str)] = &[#((#garde_names, #field_names)),*];
+
+                let value: #struct_name #ty_generics = ::core::clone::Clone::clone(self).into();
+                let mut errors = ::std::collections::HashMap::new();
+                let ::core::result::Result::Err(report) = ::gpui_form::garde::Validate::validate(&value) else {
+                    return errors;
+                };
+                for (path, error) in report.iter() {
+                    let path = path.to_string();
+                    let key = path
+                        .split(['.', '[', ']'])
+                        .find(|part| !part.is_empty())
+                        .unwrap_or_default();
+                    let field = FIELDS
+                        .iter()
+                        .find(|(garde_name, _)| *garde_name == key)
+                        .map_or("", |(_, field)| *field);
+                    let message = if path.trim_matches(['[', ']']) == key {
+                        error.to_string()
+                    } else {
+                        format!("{path}: {error}")
+                    };
+                    errors
+                        .entry(field)
+                        .or_insert_with(::std::vec::Vec::new)
+                        .push(message);
+                }
+                errors
+            }
+        }
+    })
+}
+
+/// What the `validate(...)` rules of a field look at, following its component.
+#[derive(Clone, Copy, PartialEq)]
+enum ValidatedValue {
+    /// Text, converted to a `String`.
+    Text,
+    /// A number, compared to `min` and `max`.
+    Number,
+    /// A collection, whose length `min_len` and `max_len` bound.
+    Collection,
+    /// Anything else, which can only be `required` when optional.
+    Other,
+}
+
+impl ValidatedValue {
+    fn of(component: &Components) -> Self {
+        match component {
+            Components::Input(_) | Components::Textarea(_) | Components::Otp(_) => Self::Text,
+            Components::NumberInput | Components::Slider(_) => Self::Number,
+            Components::MultiSelect | Components::List(_) => Self::Collection,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Expression checking the `rules` of a field on `self`, a value holder, to an
+/// `Option<ValidationError>`. `holder_optional` tells whether the value holder keeps the
+/// field an `Option`. The first failing rule is reported, in the order they are listed in
+/// `ValidationRules`, and only `required` applies to empty values.
+fn field_validation(
+    field_ident: &Ident,
+    component: &Components,
+    rules: &ValidationRules,
+    holder_optional: bool,
+) -> darling::Result<TokenStream> {
+    let value_kind = ValidatedValue::of(component);
+    let mut errors = darling::Error::accumulator();
+    if (rules.min_len.is_some() || rules.max_len.is_some())
+        && !matches!(
+            value_kind,
+            ValidatedValue::Text | ValidatedValue::Collection
+        )
+    {
+        errors.push(
+            darling::Error::custom(
+                "`min_len` and `max_len` only apply to `input`, `textarea`, `otp`, `multi_select` and `list` fields",
+            )
+            .with_span(field_ident),
+        );
+    }
+    if rules.pattern.is_some() && value_kind != ValidatedValue::Text {
+        errors.push(
+            darling::Error::custom(
+                "`pattern` only applies to `input`, `textarea` and `otp` fields",
+            )
+            .with_span(field_ident),
+        );
+    }
+    if (rules.min.is_some() || rules.max.is_some()) && value_kind != ValidatedValue::Number {
+        errors.push(
+            darling::Error::custom(
+                "`min` and `max` only apply to `number_input` and `slider` fields",
+            )
+            .with_span(field_ident),
+        );
+    }
+    if rules.required
+        && !holder_optional
+        && matches!(value_kind, ValidatedValue::Number | ValidatedValue::Other)
+    {
+        errors.push(
+            darling::Error::custom(
+                "`required` only applies to text, collection and optional fields",
+            )
+            .with_span(field_ident),
+        );
+    }
+    errors.finish()?;
+
+    let required = if rules.required {
+        quote! { ::core::option::Option::Some(::gpui_form::ValidationError::Required) }
+    } else {
+        quote! { ::core::option::Option::None }
+    };
+    let length = match value_kind {
+        ValidatedValue::Text => quote! { value.chars().count() },
+        _ => quote! { value.len() },
+    };
+
+    let mut checks = Vec::new();
+    if let Some(min_len) = rules.min_len {
+        checks.push((
+            quote! { #length < #min_len },
+            quote! { TooShort { min_len: #min_len } },
+        ));
+    }
+    if let Some(max_len) = rules.max_len {
+        checks.push((
+            quote! { #length > #max_len },
+            quote! { TooLong { max_len: #max_len } },
+        ));
+    }
+    if let Some(min) = &rules.min {
+        let min_str = min.to_token_stream().to_string();
+        checks.push((
+            quote! { value < &(#min) },
+            quote! { TooSmall { min: #min_str } },
+        ));
+    }
+    if let Some(max) = &rules.max {
+        let max_str = max.to_token_stream().to_string();
+        checks.push((
+            quote! { value > &(#max) },
+            quote! { TooLarge { max: #max_str } },
+        ));
+    }
+    let pattern_static = rules.pattern.as_ref().map(|pattern| {
+        checks.push((
+            quote! { !PATTERN.get_or_init(|| ::gpui_form::regex::Regex::new(#pattern).unwrap()).is_match(&value) },
+            quote! { PatternMismatch { pattern: #pattern } },
+        ));
+        quote! {
+            static PATTERN: ::std::sync::OnceLock<::gpui_form::regex::Regex> =
+                ::std::sync::OnceLock::new();
+        }
+    });
+    let checks = checks.iter().map(|(condition, error)| {
+        quote! {
+            if #condition {
+                ::core::option::Option::Some(::gpui_form::ValidationError::#error)
+            } else
+        }
+    });
+    let check = match value_kind {
+        ValidatedValue::Text => quote! {
+            let value: ::std::string::String = ::core::clone::Clone::clone(value).into();
+            if value.trim().is_empty() {
+                #required
+            } else #(#checks)* {
+                ::core::option::Option::None
+            }
+        },
+        ValidatedValue::Collection => quote! {
+            if value.is_empty() {
+                #required
+            } else #(#checks)* {
+                ::core::option::Option::None
+            }
+        },
+        ValidatedValue::Number | ValidatedValue::Other => quote! {
+            #(#checks)* {
+                ::core::option::Option::None
+            }
+        },
+    };
+
+    Ok(if holder_optional {
+        quote! {{
+            #pattern_static
+            match &self.#field_ident {
+                ::core::option::Option::None => #required,
+                ::core::option::Option::Some(value) => { #check }
+            }
+        }}
+    } else {
+        quote! {{
+            #pattern_static
+            let value = &self.#field_ident;
+            #check
+        }}
+    })
+}
+
+/// `XFormErrors` and `XFormValueHolder::validate`, checking the `#[gpui_form(validate(...))]`
+/// rules of `fields` then calling the struct's `validate_with`, for structs with either.
+pub(super) fn validate_rules(
+    parsed: &ComponentStruct,
+    fields: &[ComponentField],
+    should_be_unwrapped: &HashMap<String, bool>,
+) -> darling::Result<Option<TokenStream>> {
+    if parsed.validate_with.is_none() && fields.iter().all(|field| field.validate.is_none()) {
+        return Ok(None);
+    }
+
+    let struct_name = &parsed.ident;
+    let value_holder_name = format_ident!("{}FormValueHolder", struct_name);
+    let errors_name = format_ident!("{}FormErrors", struct_name);
+    let type_params: Vec<&Ident> = parsed
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+
+    let mut errors = darling::Error::accumulator();
+    let mut error_fields = Vec::new();
+    let mut validations = Vec::new();
+    let mut bounds: Vec<syn::WherePredicate> = Vec::new();
+    for field in fields {
+        let Some(field_ident) = field.ident.as_ref() else {
+            continue;
+        };
+        let Some(component) = field.component.as_ref() else {
+            if field.validate.is_some() {
+                errors.push(
+                    darling::Error::custom("`validate` needs a `component(...)`")
+                        .with_span(field_ident),
+                );
+            }
+            continue;
+        };
+        error_fields.push(field_ident);
+        let Some(rules) = &field.validate else {
+            validations.push(quote! { ::core::option::Option::None });
+            continue;
+        };
+
+        let unwrapped = should_be_unwrapped
+            .get(&field_ident.to_string())
+            .copied()
+            .unwrap_or_default();
+        let holder_optional = is_option(&field.ty) && !unwrapped;
+        validations.push(
+            errors
+                .handle(field_validation(
+                    field_ident,
+                    component,
+                    rules,
+                    holder_optional,
+                ))
+                .unwrap_or_default(),
+        );
+
+        // fields typed by a type parameter need it to allow their checks
+        let base_type = option_inner_type(&field.ty).unwrap_or(&field.ty);
+        if let Type::Path(type_path) = base_type
+            && let Some(ident) = type_path.path.get_ident()
+            && type_params.contains(&ident)
+        {
+            match ValidatedValue::of(component) {
+                ValidatedValue::Text => bounds.push(syn::parse_quote! {
+                    #ident: ::core::clone::Clone + ::core::convert::Into<::std::string::String>
+                }),
+                ValidatedValue::Number => bounds.push(syn::parse_quote! {
+                    #ident: ::core::cmp::PartialOrd
+                }),
+                ValidatedValue::Collection | ValidatedValue::Other => {},
+            }
+        }
+    }
+    errors.finish()?;
+
+    let generics = &parsed.generics;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let mut bounded_generics = generics.clone();
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .extend(bounds);
+    let (_, _, where_clause) = bounded_generics.split_for_impl();
+
+    let is_empty = if error_fields.is_empty() {
+        quote! { true }
+    } else {
+        quote! { #(self.#error_fields.is_none())&&* }
+    };
+    let field_errors = quote! {
+        #errors_name {
+            #(#error_fields: #validations,)*
+        }
+    };
+    let errors = match &parsed.validate_with {
+        Some(validate_with) => {
+            // `Self` is the struct, not the value holder the call ends up in
+            let validate_with = match validate_with.segments.first() {
+                Some(segment) if segment.ident == "Self" && validate_with.segments.len() > 1 => {
+                    let rest = validate_with.segments.iter().skip(1);
+                    quote! { <#struct_name #ty_generics>::#(#rest)::* }
+                },
+                _ => validate_with.to_token_stream(),
+            };
+            quote! {
+                let mut errors = #field_errors;
+                if errors.is_empty() {
+                    #validate_with(self, &mut errors);
+                }
+                errors
+            }
+        },
+        None => field_errors,
+    };
+
+    Ok(Some(quote! {
+        /// Errors of the fields of a value holder, `None` for the fields passing their
+        /// `validate(...)` rules and the struct's `validate_with`.
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct #errors_name {
+            #(pub #error_fields: ::core::option::Option<::gpui_form::ValidationError>,)*
+        }
+
+        impl #errors_name {
+            /// Whether every field passes its rules.
+            pub fn is_empty(&self) -> bool {
+                #is_empty
+            }
+        }
+
+        impl #impl_generics #value_holder_name #ty_generics #where_clause {
+            /// Checks the `validate(...)` rules of the fields, then the struct's
+            /// `validate_with` once they all pass.
+            #[allow(unused_variables)]
+            pub fn validate(&self) -> #errors_name {
+                #errors
+            }
+        }
+    }))
+}
+
+/// A field with `validate_async`, which the generated `XFormState` runs on its changes.
+pub(super) struct AsyncValidationField<'a> {
+    pub(super) field_ident: &'a Ident,
+    /// The field's `InputState` in `XFormFields`.
+    pub(super) state_ident: ComponentFieldName,
+    pub(super) validator: &'a syn::Path,
+    pub(super) debounce: TokenStream,
+}
+
+pub(super) fn async_validations(
+    fields: &[ComponentField],
+) -> darling::Result<Vec<AsyncValidationField<'_>>> {
+    let mut errors = darling::Error::accumulator();
+    let mut async_validations = Vec::new();
+    for field in fields {
+        let Some(field_ident) = field.ident.as_ref() else {
+            continue;
+        };
+        let Some(validator) = &field.validate_async else {
+            if field.debounce_ms.is_some() {
+                errors.push(
+                    darling::Error::custom("`debounce_ms` needs `validate_async`")
+                        .with_span(field_ident),
+                );
+            }
+            continue;
+        };
+        let Some(component @ (Components::Input(_) | Components::Textarea(_))) = &field.component
+        else {
+            errors.push(
+                darling::Error::custom(
+                    "`validate_async` only applies to `input` and `textarea` fields",
+                )
+                .with_span(field_ident),
+            );
+            continue;
+        };
+
+        let component_name: &'static str = ComponentsDiscriminants::from(component).into();
+        let debounce = match field.debounce_ms {
+            Some(debounce_ms) => quote! { ::core::time::Duration::from_millis(#debounce_ms) },
+            None => quote! { ::gpui_form::AsyncValidation::DEFAULT_DEBOUNCE },
+        };
+        async_validations.push(AsyncValidationField {
+            field_ident,
+            state_ident: ComponentFieldName::new(component_name, &field_ident.to_string()),
+            validator,
+            debounce,
+        });
+    }
+    errors.finish_with(async_validations)
+}
@@ -0,0 +1,16 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(Default, PartialEq)]
+pub enum Role {
+    #[default]
+    Admin,
+    Guest,
+}
+
+#[derive(GpuiForm)]
+pub struct Account {
+    #[gpui_form(component(dropdown(index = Role::Guest, default)))]
+    pub role: Role,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub enum Never {}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(PartialEq)]
+pub enum Network {
+    Visa,
+    Mastercard,
+}
+
+#[derive(GpuiForm)]
+#[gpui_form(entity)]
+pub enum Payment {
+    Card {
+        #[gpui_form(component(dropdown(partial)))]
+        network: Network,
+    },
+    Cash,
+}
+
+fn main() {}
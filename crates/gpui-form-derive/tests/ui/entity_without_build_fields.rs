@@ -0,0 +1,16 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(PartialEq)]
+pub enum Country {
+    France,
+    Japan,
+}
+
+#[derive(GpuiForm)]
+#[gpui_form(entity)]
+pub struct Address {
+    #[gpui_form(component(dropdown(partial)))]
+    pub country: Country,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub enum Shape {
+    Circle {
+        #[gpui_form(component(input))]
+        radius: String,
+    },
+    Variant {
+        #[gpui_form(component(input))]
+        name: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub enum Either<L, R> {
+    Left {
+        #[gpui_form(component(input))]
+        value: L,
+    },
+    Right {
+        #[gpui_form(component(input))]
+        value: R,
+    },
+}
+
+fn main() {}
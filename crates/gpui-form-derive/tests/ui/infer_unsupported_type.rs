@@ -0,0 +1,11 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(infer)]
+pub struct Settings {
+    pub name: String,
+    pub range: (u8, u8),
+    pub tags: Vec<String>,
+}
+
+fn main() {}
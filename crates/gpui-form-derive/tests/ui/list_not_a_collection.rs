@@ -0,0 +1,9 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub struct Todo {
+    #[gpui_form(component(list(item = input)))]
+    pub items: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use gpui_form_derive::GpuiForm;
+
+pub enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(GpuiForm)]
+pub struct Account {
+    #[gpui_form(component(multi_select))]
+    pub role: Role,
+}
+
+fn main() {}
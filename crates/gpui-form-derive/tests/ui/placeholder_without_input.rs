@@ -0,0 +1,9 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub struct Terms {
+    #[gpui_form(component(checkbox), placeholder = "Accept")]
+    pub accepted: bool,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use gpui_form_derive::GpuiForm;
+
+pub enum Size {
+    Small,
+    Large,
+}
+
+#[derive(GpuiForm)]
+pub struct Shirt {
+    #[gpui_form(component(radio_group(horizontal, vertical)))]
+    pub size: Size,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(register(Settings))]
+pub struct Settings {
+    #[gpui_form(component(number_input))]
+    pub threshold: u32,
+}
+
+fn main() {}
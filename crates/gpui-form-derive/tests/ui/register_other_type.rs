@@ -0,0 +1,10 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(register(Options<u32>, Settings<u32, u64>))]
+pub struct Settings<T> {
+    #[gpui_form(component(number_input))]
+    pub threshold: T,
+}
+
+fn main() {}
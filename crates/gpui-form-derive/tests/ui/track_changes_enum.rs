@@ -0,0 +1,13 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(track_changes)]
+pub enum Payment {
+    Cash,
+    Card {
+        #[gpui_form(component(input))]
+        number: String,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(transparent)]
+pub struct Range(u32, u32);
+
+fn main() {}
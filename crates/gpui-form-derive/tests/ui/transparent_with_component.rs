@@ -0,0 +1,7 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(transparent)]
+pub struct Email(#[gpui_form(component(input))] String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub struct User {
+    #[gpui_form(component(rich_text))]
+    pub bio: String,
+}
+
+fn main() {}
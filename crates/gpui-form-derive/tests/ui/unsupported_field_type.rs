@@ -0,0 +1,9 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub struct Tags {
+    #[gpui_form(component(input))]
+    pub tags: [String; 2],
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use gpui_form_derive::GpuiForm;
+
+async fn accepted(_accepted: bool) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(GpuiForm)]
+#[gpui_form(entity)]
+pub struct Terms {
+    #[gpui_form(component(checkbox), validate_async = accepted)]
+    pub accepted: bool,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use gpui_form_derive::GpuiForm;
+
+async fn username_available(_username: String) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(GpuiForm)]
+pub struct Signup {
+    #[gpui_form(component(input), validate_async = username_available)]
+    pub username: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+pub struct Signup {
+    #[gpui_form(component(input), validate(min = 3))]
+    pub username: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use gpui_form_derive::GpuiForm;
+
+#[derive(GpuiForm)]
+#[gpui_form(validate_with = Self::check)]
+pub enum Payment {
+    Cash,
+    Card {
+        #[gpui_form(component(input))]
+        number: String,
+    },
+}
+
+fn main() {}
@@ -1,32 +1,192 @@
 use gpui_form_core::components::ComponentsBehaviour;
-use gpui_form_core::registry::GpuiFormShape;
+use gpui_form_core::registry::{FieldVariant, GpuiFormShape};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 use super::implementations::{
-    ComponentIdentities, ComponentShape, FieldCodeGenerator, checkbox::CheckboxCodeGenerator,
-    date_picker::DatePickerCodeGenerator, dropdown::DropdownCodeGenerator,
-    input::InputCodeGenerator, number_input::NumberInputCodeGenerator, switch::SwitchCodeGenerator,
+    ComponentIdentities, ComponentShape, FieldCodeGenerator, GeneratedSubscription,
+    checkbox::CheckboxCodeGenerator, color_picker::ColorPickerCodeGenerator,
+    date_picker::DatePickerCodeGenerator, date_range_picker::DateRangePickerCodeGenerator,
+    datetime_picker::DatetimePickerCodeGenerator, dropdown::DropdownCodeGenerator,
+    input::InputCodeGenerator, list::ListCodeGenerator, multi_select::MultiSelectCodeGenerator,
+    nested::NestedCodeGenerator, number_input::NumberInputCodeGenerator, otp::OtpCodeGenerator,
+    radio_group::RadioGroupCodeGenerator, slider::SliderCodeGenerator, switch::SwitchCodeGenerator,
+    textarea::TextareaCodeGenerator, time_picker::TimePickerCodeGenerator,
+    variant::VariantCodeGenerator, variant_selector::VariantSelectorCodeGenerator,
 };
 
 macro_rules! field_generator {
     ($behaviour:expr) => {{
         match $behaviour {
-            ComponentsBehaviour::Input => Box::new(InputCodeGenerator),
+            ComponentsBehaviour::Input(_) => Box::new(InputCodeGenerator),
+            ComponentsBehaviour::Textarea(_) => Box::new(TextareaCodeGenerator),
+            ComponentsBehaviour::Otp(_) => Box::new(OtpCodeGenerator),
             ComponentsBehaviour::NumberInput => Box::new(NumberInputCodeGenerator),
             ComponentsBehaviour::Checkbox => Box::new(CheckboxCodeGenerator),
             ComponentsBehaviour::Switch => Box::new(SwitchCodeGenerator),
             ComponentsBehaviour::Dropdown(_) => Box::new(DropdownCodeGenerator),
+            ComponentsBehaviour::MultiSelect => Box::new(MultiSelectCodeGenerator),
+            ComponentsBehaviour::RadioGroup(_) => Box::new(RadioGroupCodeGenerator),
             ComponentsBehaviour::DatePicker => Box::new(DatePickerCodeGenerator),
+            ComponentsBehaviour::DateRangePicker(_) => Box::new(DateRangePickerCodeGenerator),
+            ComponentsBehaviour::TimePicker(_) => Box::new(TimePickerCodeGenerator),
+            ComponentsBehaviour::DatetimePicker(_) => Box::new(DatetimePickerCodeGenerator),
+            ComponentsBehaviour::ColorPicker => Box::new(ColorPickerCodeGenerator),
+            ComponentsBehaviour::Slider(_) => Box::new(SliderCodeGenerator),
+            ComponentsBehaviour::Nested => Box::new(NestedCodeGenerator),
+            ComponentsBehaviour::List(_) => Box::new(ListCodeGenerator),
+            ComponentsBehaviour::VariantSelector => Box::new(VariantSelectorCodeGenerator),
+            ComponentsBehaviour::Variant => Box::new(VariantCodeGenerator),
         }
     }};
 }
 
-pub struct ShapeIdentities<'a>(&'a GpuiFormShape);
+pub(crate) fn field_generator_for(field: &FieldVariant) -> Box<dyn FieldCodeGenerator> {
+    field_generator!(field.behaviour)
+}
+
+/// Where a shape's states and values live inside the generated form, nested shapes being
+/// reached through their parent's `XFormFields` and value holder.
+struct NestedScope {
+    fields_path: TokenStream,
+    data_path: TokenStream,
+    local_prefix: TokenStream,
+    name_prefix: String,
+}
+
+pub struct ShapeIdentities<'a>(&'a GpuiFormShape, Option<NestedScope>);
 
 impl<'a> ShapeIdentities<'a> {
     pub fn new(shape_data: &'a GpuiFormShape) -> Self {
-        Self(shape_data)
+        Self(shape_data, None)
+    }
+
+    /// Identities for the shape of `field`, a `component(nested)` field of this shape.
+    pub fn nested(&self, shape_data: &'a GpuiFormShape, field: &FieldVariant) -> Self {
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+        let fields_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, field.behaviour))
+                .unwrap();
+
+        let fields_path = self.fields_path();
+        let data_path = self.data_path();
+        let local_prefix = self.local_prefix();
+        let data_path = if field.optional {
+            let field_type = syn::parse_str::<syn::Type>(field.field_type).unwrap();
+            quote! { #data_path.#field_name_ident.get_or_insert_with(|| #field_type::default().into()) }
+        } else {
+            quote! { #data_path.#field_name_ident }
+        };
+
+        Self(
+            shape_data,
+            Some(NestedScope {
+                fields_path: quote! { #fields_path.#fields_ident },
+                data_path,
+                local_prefix: quote! { #local_prefix #fields_ident. },
+                name_prefix: self.scoped_name(field.field_name),
+            }),
+        )
+    }
+
+    /// Identities for the shape of a `list(item = nested)` row of this shape, the row being
+    /// picked by an `index` binding and reached as `row` while the form is being built.
+    pub fn list_row(&self, shape_data: &'a GpuiFormShape, field: &FieldVariant) -> Self {
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+        let fields_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, field.behaviour))
+                .unwrap();
+
+        let fields_path = self.fields_path();
+        let data_path = self.data_path();
+        let data_path = if field.optional {
+            quote! { #data_path.#field_name_ident.get_or_insert_default()[index] }
+        } else {
+            quote! { #data_path.#field_name_ident[index] }
+        };
+
+        Self(
+            shape_data,
+            Some(NestedScope {
+                fields_path: quote! { #fields_path.#fields_ident[index] },
+                data_path,
+                local_prefix: quote! { row. },
+                name_prefix: self.scoped_name(field.field_name),
+            }),
+        )
+    }
+
+    /// Path to the field states from `self`, e.g. `self.#fields_path.name_input`.
+    pub fn fields_path(&self) -> TokenStream {
+        match &self.1 {
+            Some(scope) => scope.fields_path.clone(),
+            None => quote! { fields },
+        }
+    }
+
+    /// Path to the edited values from `self`, e.g. `self.#data_path.name`.
+    pub fn data_path(&self) -> TokenStream {
+        match &self.1 {
+            Some(scope) => scope.data_path.clone(),
+            None => quote! { current_data },
+        }
+    }
+
+    /// Prefix of the field states while they are still locals of the form's `new`.
+    pub fn local_prefix(&self) -> TokenStream {
+        match &self.1 {
+            Some(scope) => scope.local_prefix.clone(),
+            None => quote! {},
+        }
+    }
+
+    /// Errors of `field` from the `errors` the form's render gets out of `validate_fields`,
+    /// and from the `form_errors` it gets out of `validate`, shown under it. Only top level
+    /// fields have their errors, nested ones being reported under their parent's.
+    pub fn field_errors(&self, field: &FieldVariant) -> TokenStream {
+        if self.1.is_some() {
+            return TokenStream::new();
+        }
+        let field_name = field.field_name;
+        let garde_errors = self.0.validated.then(|| {
+            quote! {
+                .children(errors.get(#field_name).into_iter().flatten().map(|error| {
+                    ::gpui::div().text_xs().text_color(::gpui::red()).child(error.clone())
+                }))
+            }
+        });
+        let rule_errors = self.0.checked.then(|| {
+            let field_ident = format_ident!("{}", field_name);
+            quote! {
+                .children(form_errors.#field_ident.as_ref().map(|error| {
+                    ::gpui::div().text_xs().text_color(::gpui::red()).child(error.to_string())
+                }))
+            }
+        });
+        quote! { #garde_errors #rule_errors }
+    }
+
+    /// Marker shown under `field` when it is in the `changed` fields the form's render gets
+    /// out of `changed_fields`. Only top level fields are marked, changes to nested ones
+    /// marking their parent.
+    pub fn field_modified(&self, field: &FieldVariant) -> TokenStream {
+        if !self.0.tracked || self.1.is_some() {
+            return TokenStream::new();
+        }
+        let field_name = field.field_name;
+        quote! {
+            .children(changed.contains(&#field_name).then(|| {
+                ::gpui::div().text_xs().text_color(::gpui::blue()).child("Modified")
+            }))
+        }
+    }
+
+    /// Keeps generated handler names and element ids unique across nested shapes.
+    pub fn scoped_name(&self, name: &str) -> String {
+        match &self.1 {
+            Some(scope) => format!("{}_{}", scope.name_prefix, name),
+            None => name.to_owned(),
+        }
     }
 }
 
@@ -34,6 +194,10 @@ impl<'a> ComponentIdentities for ShapeIdentities<'a> {
     fn struct_name(&self) -> &'static str {
         self.0.struct_name
     }
+
+    fn generic_args(&self) -> Option<&'static str> {
+        self.0.generic_args
+    }
 }
 
 pub struct FormShapeAdapter<'a> {
@@ -66,7 +230,7 @@ impl<'a> ComponentShape for FormShapeAdapter<'a> {
     }
 
     fn field_initializers(&self) -> Option<TokenStream> {
-        let x: proc_macro2::TokenStream = self
+        let mut x: proc_macro2::TokenStream = self
             .shape_data
             .components
             .iter()
@@ -75,6 +239,9 @@ impl<'a> ComponentShape for FormShapeAdapter<'a> {
                 generator.generate_field_initializers(field, &self.identities)
             })
             .collect();
+        if self.shape_data.generic_args.is_some() {
+            x.extend(quote! { _marker: ::core::marker::PhantomData, });
+        }
 
         if x.is_empty() { None } else { Some(x) }
     }
@@ -105,7 +272,7 @@ impl<'a> ComponentShape for FormShapeAdapter<'a> {
     }
 
     fn subscription_calls(&self) -> Option<proc_macro2::TokenStream> {
-        let calls: Vec<TokenStream> = self
+        let calls = self
             .shape_data
             .components
             .iter()
@@ -113,15 +280,27 @@ impl<'a> ComponentShape for FormShapeAdapter<'a> {
                 let generator: Box<dyn FieldCodeGenerator> = field_generator!(field.behaviour);
                 generator.generate_subscription(field, &self.identities)
             })
-            .flat_map(|sub| sub.calls)
-            .collect();
+            .fold(GeneratedSubscription::default(), |mut acc, sub| {
+                acc.calls.extend(sub.calls);
+                acc.call_groups.extend(sub.call_groups);
+                acc
+            });
 
-        if calls.is_empty() {
+        if calls.calls.is_empty() && calls.call_groups.is_empty() {
             None
-        } else {
+        } else if calls.call_groups.is_empty() {
+            let calls = calls.calls;
             Some(quote! {
                 let _subscriptions = vec![#(#calls),*];
             })
+        } else {
+            let GeneratedSubscription {
+                calls, call_groups, ..
+            } = calls;
+            Some(quote! {
+                let mut _subscriptions = vec![#(#calls),*];
+                #(_subscriptions.extend(#call_groups);)*
+            })
         }
     }
 
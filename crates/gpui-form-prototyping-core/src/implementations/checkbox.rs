@@ -1,5 +1,5 @@
 use gpui_form_core::registry::FieldVariant;
-use heck::{ToKebabCase as _, ToPascalCase as _};
+use heck::ToKebabCase as _;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -32,12 +32,14 @@ impl FieldCodeGenerator for CheckboxCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> TokenStream {
-        let ftl_label_ident = component.ftl_label_ident();
-        let ftl_description_ident = component.ftl_description_ident();
+        let data_path = component.data_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
         let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
-        let field_name_pascal_case_ident =
-            syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
 
         let component_gpui_type = field.behaviour.as_component_ident();
 
@@ -46,14 +48,16 @@ impl FieldCodeGenerator for CheckboxCodeGenerator {
         quote! {
             .child(
                 form_field()
-                    .label(#ftl_label_ident::#field_name_pascal_case_ident.to_string())
-                    .description(#ftl_description_ident::#field_name_pascal_case_ident.to_string())
+                    .label(#label)
+                    #description
                     .child(#component_gpui_type::new(#checkbox_id_str)
-                    .checked(self.current_data.#field_name_ident)
-                    .on_click(cx.listener(|v, _, _, _| {
-                        v.current_data.#field_name_ident = !v.current_data.#field_name_ident;
+                    .checked(self.#data_path.#field_name_ident)
+                    .on_click(cx.listener(move |v, _, _, _| {
+                        v.#data_path.#field_name_ident = !v.#data_path.#field_name_ident;
                     })),
                 )
+                    #errors
+                    #modified
             )
         }
     }
@@ -1,5 +1,4 @@
 use gpui_form_core::registry::FieldVariant;
-use heck::ToPascalCase as _;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -15,8 +14,8 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let form_components_struct_ident = component.struct_form_components_ident();
-        let suffix = field.behaviour.to_string();
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
         let var_name_ident =
             syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
         let fn_name_ident =
@@ -33,7 +32,7 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         field: &FieldVariant,
         _component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
@@ -45,11 +44,13 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> TokenStream {
-        let ftl_label_ident = component.ftl_label_ident();
-        let ftl_description_ident = component.ftl_description_ident();
-        let field_name_pascal_case_ident =
-            syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
 
         let component_gpui_type = field.behaviour.as_component_ident();
 
@@ -60,9 +61,11 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         quote! {
             .child(
                 form_field()
-                  .label(#ftl_label_ident::#field_name_pascal_case_ident.to_string())
-                  .description(#ftl_description_ident::#field_name_pascal_case_ident.to_string())
-                  .child(#component_gpui_type::new(&self.fields.#field_in_struct_name_ident))
+                  .label(#label)
+                  #description
+                  .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                  #errors
+                  #modified
             )
         }
     }
@@ -70,13 +73,15 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
     fn generate_focusable_cycle(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
         let x = quote! {
-          self.fields.#field_var_name_ident.focus_handle(cx),
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
         };
         Some(x)
     }
@@ -84,18 +89,24 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
     fn generate_subscription(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<GeneratedSubscription> {
-        let suffix = field.behaviour.to_string();
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
-        let event_handler_fn_name = format!("on_{}_date_picker_event", field.field_name);
+        let event_handler_fn_name = format!(
+            "on_{}_date_picker_event",
+            component.scoped_name(field.field_name)
+        );
         let event_handler_fn_name_ident =
             syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
 
         let calls = vec![
-            quote! { cx.subscribe_in(&#field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
         ];
 
         let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
@@ -103,13 +114,13 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         #[cfg(all(feature = "jiff", not(feature = "chrono")))]
         let value_assign = if !field.optional {
             quote! {
-                self.current_data.#field_name_ident =
+                self.#data_path.#field_name_ident =
                     jiff::civil::DateTime::from_str(&date.to_owned().to_string())
                         .unwrap_or_default();
             }
         } else {
             quote! {
-                self.current_data.#field_name_ident =
+                self.#data_path.#field_name_ident =
                     jiff::civil::DateTime::from_str(&date.to_owned().to_string()).ok();
             }
         };
@@ -117,13 +128,13 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         #[cfg(all(feature = "chrono", not(feature = "jiff")))]
         let value_assign = if !field.optional {
             quote! {
-                self.current_data.#field_name_ident =
+                self.#data_path.#field_name_ident =
                     chrono::NaiveDate::parse_from_str(&date.to_owned().to_string(), "%Y-%m-%d")
                         .unwrap_or_default();
             }
         } else {
             quote! {
-                self.current_data.#field_name_ident =
+                self.#data_path.#field_name_ident =
                     chrono::NaiveDate::parse_from_str(&date.to_owned().to_string(), "%Y-%m-%d")
                         .ok();
             }
@@ -148,6 +159,7 @@ impl FieldCodeGenerator for DatePickerCodeGenerator {
         Some(GeneratedSubscription {
             calls,
             handlers: vec![handler],
+            ..Default::default()
         })
     }
 }
@@ -0,0 +1,189 @@
+use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct DateRangePickerCodeGenerator;
+
+impl FieldCodeGenerator for DateRangePickerCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        Some(quote! {
+            let #var_name_ident =
+                cx.new(|cx| #form_components_struct_ident::#fn_name_ident(window, cx));
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+
+        let presets = if let ComponentsBehaviour::DateRangePicker(range_config) = &field.behaviour
+            && range_config.presets
+        {
+            let form_components_struct_ident = component.struct_form_components_path();
+            let presets_fn_ident =
+                syn::parse_str::<syn::Ident>(&format!("{}_presets", field_in_struct_name_str))
+                    .unwrap();
+            quote! { .presets(#form_components_struct_ident::#presets_fn_ident()) }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            .child(
+                form_field()
+                  .label(#label)
+                  #description
+                  .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident)#presets)
+                  #errors
+                  #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let x = quote! {
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let event_handler_fn_name = format!(
+            "on_{}_date_range_picker_event",
+            component.scoped_name(field.field_name)
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        // the value holder keeps either a `(start, end)` pair or a `Range<_>`
+        let range_value = if field.field_type.contains("Range") {
+            quote! { start..end }
+        } else {
+            quote! { (start, end) }
+        };
+
+        #[cfg(all(feature = "jiff", not(feature = "chrono")))]
+        let bounds = quote! {
+            let (Ok(start), Ok(end)) = (
+                start.to_string().parse::<jiff::civil::Date>(),
+                end.to_string().parse::<jiff::civil::Date>(),
+            ) else {
+                return;
+            };
+        };
+
+        #[cfg(all(feature = "chrono", not(feature = "jiff")))]
+        let bounds = quote! {
+            let (start, end) = (*start, *end);
+        };
+
+        let value_assign = if !field.optional {
+            quote! {
+                if let Date::Range(Some(start), Some(end)) = date {
+                    #bounds
+                    self.#data_path.#field_name_ident = #range_value;
+                }
+            }
+        } else {
+            quote! {
+                match date {
+                    Date::Range(Some(start), Some(end)) => {
+                        #bounds
+                        self.#data_path.#field_name_ident = Some(#range_value);
+                    }
+                    _ => self.#data_path.#field_name_ident = None,
+                }
+            }
+        };
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                _this: &Entity<DatePickerState>,
+                event: &DatePickerEvent,
+                _: &mut Window,
+                _: &mut Context<Self>,
+            ) {
+                match event {
+                    DatePickerEvent::Change(date) => {
+                        #value_assign
+                    }
+                }
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,263 @@
+use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{
+    FieldCodeGenerator, GeneratedSubscription,
+    time_picker::{time_part_children, time_part_idents, time_value_tokens},
+};
+
+pub struct DatetimePickerCodeGenerator;
+
+fn seconds(field: &FieldVariant) -> bool {
+    match &field.behaviour {
+        ComponentsBehaviour::DatetimePicker(datetime_config) => datetime_config.seconds,
+        _ => panic!("Expected DatetimePicker behaviour"),
+    }
+}
+
+fn is_zoned(field: &FieldVariant) -> bool {
+    match &field.behaviour {
+        ComponentsBehaviour::DatetimePicker(datetime_config) => datetime_config.zoned,
+        _ => panic!("Expected DatetimePicker behaviour"),
+    }
+}
+
+fn date_ident(field: &FieldVariant) -> syn::Ident {
+    let suffix = field.component_suffix();
+    syn::parse_str::<syn::Ident>(&format!("{}_{}_date", field.field_name, suffix)).unwrap()
+}
+
+impl FieldCodeGenerator for DatetimePickerCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let calls = std::iter::once(date_ident(field))
+            .chain(time_part_idents(field, seconds(field)))
+            .map(|ident| {
+                quote! {
+                    let #ident = cx.new(|cx| #form_components_struct_ident::#ident(window, cx));
+                }
+            });
+
+        Some(quote! { #(#calls)* })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let date_ident = date_ident(field);
+        let part_idents = time_part_idents(field, seconds(field));
+
+        Some(quote! { #date_ident, #(#part_idents,)* })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+
+        let date_ident = date_ident(field);
+        let children = time_part_children(component, &time_part_idents(field, seconds(field)));
+
+        quote! {
+            .child(
+                form_field()
+                    .label(#label)
+                    #description
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(DatePicker::new(&self.#fields_path.#date_ident))
+                            #children
+                    )
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let date_ident = date_ident(field);
+        let part_idents = time_part_idents(field, seconds(field));
+        let x = quote! {
+          self.#fields_path.#date_ident.focus_handle(cx),
+          #(self.#fields_path.#part_idents.focus_handle(cx),)*
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let fields_path = component.fields_path();
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let date_ident = date_ident(field);
+        let part_idents = time_part_idents(field, seconds(field));
+
+        let date_handler_fn_name = format!(
+            "on_{}_{}_date_event",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let date_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&date_handler_fn_name).unwrap();
+        let time_handler_fn_name = format!(
+            "on_{}_{}_time_event",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let time_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&time_handler_fn_name).unwrap();
+        let sync_fn_name = format!(
+            "sync_{}_{}",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let sync_fn_name_ident = syn::parse_str::<syn::Ident>(&sync_fn_name).unwrap();
+
+        let calls = std::iter::once(quote! {
+            cx.subscribe_in(&#local_prefix #date_ident, window, Self::#date_handler_fn_name_ident)
+        })
+        .chain(part_idents.iter().map(|part_ident| {
+            quote! { cx.subscribe_in(&#local_prefix #part_ident, window, Self::#time_handler_fn_name_ident) }
+        }))
+        .collect();
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let time = time_value_tokens(component, &part_idents);
+
+        // timezone-aware fields keep the zone of the value they are replacing
+        #[cfg(all(feature = "jiff", not(feature = "chrono")))]
+        let (datetime, zoned) = {
+            let datetime = quote! {
+                let Ok(date) = date.to_string().parse::<jiff::civil::Date>() else {
+                    return;
+                };
+                let value = date.to_datetime(time);
+            };
+            let time_zone = if !field.optional {
+                quote! { self.#data_path.#field_name_ident.time_zone().clone() }
+            } else {
+                quote! {
+                    self.#data_path
+                        .#field_name_ident
+                        .as_ref()
+                        .map(|value| value.time_zone().clone())
+                        .unwrap_or_else(jiff::tz::TimeZone::system)
+                }
+            };
+            let zoned = quote! {
+                let Ok(value) = value.to_zoned(#time_zone) else {
+                    return;
+                };
+            };
+            (datetime, is_zoned(field).then_some(zoned))
+        };
+
+        #[cfg(all(feature = "chrono", not(feature = "jiff")))]
+        let (datetime, zoned) = {
+            let datetime = quote! {
+                let value = date.and_time(time);
+            };
+            let time_zone = if !field.optional {
+                quote! { self.#data_path.#field_name_ident.timezone() }
+            } else {
+                quote! {
+                    self.#data_path
+                        .#field_name_ident
+                        .as_ref()
+                        .map(|value| value.timezone())
+                        .unwrap_or_default()
+                }
+            };
+            let zoned = quote! {
+                let Some(value) =
+                    chrono::TimeZone::from_local_datetime(&#time_zone, &value).single()
+                else {
+                    return;
+                };
+            };
+            (datetime, is_zoned(field).then_some(zoned))
+        };
+
+        let value_assign = if !field.optional {
+            quote! { self.#data_path.#field_name_ident = value; }
+        } else {
+            quote! { self.#data_path.#field_name_ident = Some(value); }
+        };
+
+        let date_handler = quote! {
+            fn #date_handler_fn_name_ident(
+                &mut self,
+                _this: &Entity<DatePickerState>,
+                event: &DatePickerEvent,
+                _: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    DatePickerEvent::Change(_) => self.#sync_fn_name_ident(cx),
+                }
+            }
+        };
+
+        let time_handler = quote! {
+            fn #time_handler_fn_name_ident(
+                &mut self,
+                _state: &Entity<InputState>,
+                event: &InputEvent,
+                _window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    InputEvent::Change => self.#sync_fn_name_ident(cx),
+                    _ => {}
+                }
+            }
+        };
+
+        let sync = quote! {
+            fn #sync_fn_name_ident(&mut self, cx: &mut Context<Self>) {
+                let Date::Single(Some(date)) = self.#fields_path.#date_ident.read(cx).date() else {
+                    return;
+                };
+                #time
+                #datetime
+                #zoned
+                #value_assign
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![date_handler, time_handler, sync],
+            ..Default::default()
+        })
+    }
+}
@@ -1,5 +1,4 @@
 use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
-use heck::ToPascalCase as _;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -19,8 +18,8 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
             return None;
         }
 
-        let form_components_struct_ident = component.struct_form_components_ident();
-        let suffix = field.behaviour.to_string();
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
         let var_name_ident =
             syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
         let fn_name_ident =
@@ -37,7 +36,7 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
         field: &FieldVariant,
         _component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
@@ -49,11 +48,13 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> TokenStream {
-        let ftl_label_ident = component.ftl_label_ident();
-        let ftl_description_ident = component.ftl_description_ident();
-        let field_name_pascal_case_ident =
-            syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
 
         let component_gpui_type = field.behaviour.as_component_ident();
 
@@ -64,9 +65,11 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
         quote! {
             .child(
                 form_field()
-                  .label(#ftl_label_ident::#field_name_pascal_case_ident.to_string())
-                  .description(#ftl_description_ident::#field_name_pascal_case_ident.to_string())
-                  .child(#component_gpui_type::new(&self.fields.#field_in_struct_name_ident))
+                  .label(#label)
+                  #description
+                  .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                  #errors
+                  #modified
             )
         }
     }
@@ -74,13 +77,15 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
     fn generate_focusable_cycle(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
         let x = quote! {
-          self.fields.#field_var_name_ident.focus_handle(cx),
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
         };
         Some(x)
     }
@@ -88,24 +93,30 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
     fn generate_subscription(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
         let struct_name_ident = field.struct_name_ident();
         let searchable = if let ComponentsBehaviour::Dropdown(dropdown_config) = &field.behaviour {
             dropdown_config.searchable
         } else {
             panic!("Expected Dropdown behaviour")
         };
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
-        let event_handler_fn_name = format!("on_{}_dropdown_event", field.field_name);
+        let event_handler_fn_name = format!(
+            "on_{}_dropdown_event",
+            component.scoped_name(field.field_name)
+        );
         let event_handler_fn_name_ident =
             syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
 
         let calls = vec![
-            quote! { cx.subscribe_in(&#field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
         ];
 
         let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
@@ -127,7 +138,7 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
                 match event {
                     DropdownEvent::Confirm(value) => {
                         if let Some(value) = value {
-                            self.current_data.#field_name_ident = value.clone().into();
+                            self.#data_path.#field_name_ident = value.clone().into();
                         }
                     },
                 }
@@ -137,6 +148,7 @@ impl FieldCodeGenerator for DropdownCodeGenerator {
         Some(GeneratedSubscription {
             calls,
             handlers: vec![handler],
+            ..Default::default()
         })
     }
 }
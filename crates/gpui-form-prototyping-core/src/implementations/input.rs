@@ -1,5 +1,4 @@
 use gpui_form_core::registry::FieldVariant;
-use heck::ToPascalCase as _;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -15,8 +14,8 @@ impl FieldCodeGenerator for InputCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let form_components_struct_ident = component.struct_form_components_ident();
-        let suffix = field.behaviour.to_string();
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
         let var_name_ident =
             syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
         let fn_name_ident =
@@ -33,7 +32,7 @@ impl FieldCodeGenerator for InputCodeGenerator {
         field: &FieldVariant,
         _component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
@@ -45,11 +44,13 @@ impl FieldCodeGenerator for InputCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> TokenStream {
-        let ftl_label_ident = component.ftl_label_ident();
-        let ftl_description_ident = component.ftl_description_ident();
-        let field_name_pascal_case_ident =
-            syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
 
         let component_gpui_type = field.behaviour.as_component_ident();
 
@@ -57,12 +58,21 @@ impl FieldCodeGenerator for InputCodeGenerator {
         let field_in_struct_name_ident =
             syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
 
+        // masked inputs get an eye button to reveal the entered value
+        let mask_toggle = if field.behaviour.masked() {
+            quote! { .mask_toggle() }
+        } else {
+            quote! {}
+        };
+
         quote! {
             .child(
                 form_field()
-                    .label(#ftl_label_ident::#field_name_pascal_case_ident.to_string())
-                    .description(#ftl_description_ident::#field_name_pascal_case_ident.to_string())
-                    .child(#component_gpui_type::new(&self.fields.#field_in_struct_name_ident))
+                    .label(#label)
+                    #description
+                    .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident)#mask_toggle)
+                    #errors
+                    #modified
             )
         }
     }
@@ -70,13 +80,15 @@ impl FieldCodeGenerator for InputCodeGenerator {
     fn generate_focusable_cycle(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
         let x = quote! {
-          self.fields.#field_var_name_ident.focus_handle(cx),
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
         };
         Some(x)
     }
@@ -84,18 +96,22 @@ impl FieldCodeGenerator for InputCodeGenerator {
     fn generate_subscription(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<GeneratedSubscription> {
-        let suffix = field.behaviour.to_string();
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
-        let event_handler_fn_name = format!("on_{}_input_event", field.field_name);
+        let event_handler_fn_name =
+            format!("on_{}_input_event", component.scoped_name(field.field_name));
         let event_handler_fn_name_ident =
             syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
 
         let calls = vec![
-            quote! { cx.subscribe_in(&#field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
         ];
 
         let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
@@ -111,7 +127,7 @@ impl FieldCodeGenerator for InputCodeGenerator {
                 match event {
                     InputEvent::Change => {
                       let text = state.read(_cx).value();
-                      self.current_data.#field_name_ident = text.to_owned().into();
+                      self.#data_path.#field_name_ident = text.to_owned().into();
                     }
                     _ => {}
                 }
@@ -121,6 +137,7 @@ impl FieldCodeGenerator for InputCodeGenerator {
         Some(GeneratedSubscription {
             calls,
             handlers: vec![handler],
+            ..Default::default()
         })
     }
 }
@@ -0,0 +1,412 @@
+use gpui_form_core::{
+    components::{ComponentsBehaviour, ListItem},
+    registry::{FieldVariant, GpuiFormShape},
+};
+use heck::ToKebabCase as _;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    code_gen::{ShapeIdentities, field_generator_for},
+    implementations::ComponentIdentities as _,
+};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct ListCodeGenerator;
+
+fn list_item(field: &FieldVariant) -> ListItem {
+    if let ComponentsBehaviour::List(options) = &field.behaviour {
+        options.item
+    } else {
+        panic!("Expected List behaviour")
+    }
+}
+
+fn nested_shape(field: &FieldVariant) -> &'static GpuiFormShape {
+    field.nested_shape().unwrap_or_else(|| {
+        panic!(
+            "No GpuiFormShape registered for the rows of list field `{}`",
+            field.field_name
+        )
+    })
+}
+
+/// Rewrites a row subscription so its handler is told which row emitted the event, the row
+/// being looked up when the event fires since rows move around.
+fn row_subscription_call(call: &TokenStream, rows_path: &TokenStream) -> TokenStream {
+    let mut call = syn::parse2::<syn::ExprMethodCall>(call.clone())
+        .expect("Expected a `cx.subscribe_in(..)` call");
+    let state = call.args[0].clone();
+    let syn::Expr::Path(handler) = &call.args[2] else {
+        panic!("Expected a `Self::handler` path");
+    };
+    let handler_ident = &handler
+        .path
+        .segments
+        .last()
+        .expect("Expected a `Self::handler` path")
+        .ident;
+    call.args[2] = syn::parse_quote! {
+        move |this, state, event, window, cx| {
+            if let Some(index) = this.#rows_path.iter().position(|row| #state == state) {
+                this.#handler_ident(index, state, event, window, cx);
+            }
+        }
+    };
+    quote! { #call }
+}
+
+/// Adds the `index` of the row to a handler generated for a row field.
+fn row_handler(handler: &TokenStream) -> TokenStream {
+    let mut handler =
+        syn::parse2::<syn::ImplItemFn>(handler.clone()).expect("Expected a handler fn");
+    handler
+        .sig
+        .inputs
+        .insert(1, syn::parse_quote! { index: usize });
+    quote! { #handler }
+}
+
+impl FieldCodeGenerator for ListCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let data_path = component.data_path();
+
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let push_fn_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}_push", field.field_name, suffix)).unwrap();
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let items = if field.optional {
+            quote! { #data_path.#field_name_ident.iter().flatten() }
+        } else {
+            quote! { #data_path.#field_name_ident.iter() }
+        };
+
+        let push_rows = match list_item(field) {
+            ListItem::Input | ListItem::NumberInput => quote! {
+                for item in #items {
+                    #form_components_struct_ident::#push_fn_ident(&mut #var_name_ident, window, cx)
+                        .update(cx, |state, cx| state.set_value(item.to_string(), window, cx));
+                }
+            },
+            ListItem::Nested => quote! {
+                for _ in #items {
+                    #form_components_struct_ident::#push_fn_ident(&mut #var_name_ident, window, cx);
+                }
+            },
+        };
+
+        Some(quote! {
+            let mut #var_name_ident = Vec::new();
+            #push_rows
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+        let scoped_name = component.scoped_name(&field_in_struct_name_str);
+        let button_id = |action: &str| format!("{}-{}", scoped_name, action).to_kebab_case();
+        let (up_id, down_id, remove_id, add_id) = (
+            button_id("up"),
+            button_id("down"),
+            button_id("remove"),
+            button_id("add"),
+        );
+        let push_handler_ident =
+            syn::parse_str::<syn::Ident>(&format!("push_{}_row", scoped_name)).unwrap();
+        let remove_handler_ident =
+            syn::parse_str::<syn::Ident>(&format!("remove_{}_row", scoped_name)).unwrap();
+        let move_handler_ident =
+            syn::parse_str::<syn::Ident>(&format!("move_{}_row", scoped_name)).unwrap();
+
+        let row_element = match list_item(field) {
+            ListItem::Input | ListItem::NumberInput => quote! {
+                #component_gpui_type::new(row)
+            },
+            ListItem::Nested => {
+                let shape = nested_shape(field);
+                let row_component = component.list_row(shape, field);
+                let children: TokenStream = shape
+                    .components
+                    .iter()
+                    .map(|row_field| {
+                        field_generator_for(row_field)
+                            .generate_render_child(row_field, &row_component)
+                    })
+                    .collect();
+                quote! {
+                    #component_gpui_type::new()
+                        .flex_1()
+                        .child(
+                            v_form()
+                                #children
+                        )
+                }
+            },
+        };
+
+        // rows get move and remove buttons, new rows are appended by the button below them
+        quote! {
+            .child(
+                form_field()
+                  .label(#label)
+                  #description
+                  .child(
+                      v_flex()
+                          .gap_2()
+                          .children(self.#fields_path.#field_in_struct_name_ident.iter().enumerate().map(|(index, row)| {
+                              h_flex()
+                                  .gap_1()
+                                  .child(#row_element)
+                                  .child(
+                                      Button::new((#up_id, index))
+                                          .ghost()
+                                          .xsmall()
+                                          .icon(IconName::ArrowUp)
+                                          .on_click(cx.listener(move |this, _, _, cx| {
+                                              this.#move_handler_ident(index, index.saturating_sub(1), cx);
+                                          })),
+                                  )
+                                  .child(
+                                      Button::new((#down_id, index))
+                                          .ghost()
+                                          .xsmall()
+                                          .icon(IconName::ArrowDown)
+                                          .on_click(cx.listener(move |this, _, _, cx| {
+                                              this.#move_handler_ident(index, index + 1, cx);
+                                          })),
+                                  )
+                                  .child(
+                                      Button::new((#remove_id, index))
+                                          .ghost()
+                                          .xsmall()
+                                          .icon(IconName::Close)
+                                          .on_click(cx.listener(move |this, _, _, cx| {
+                                              this.#remove_handler_ident(index, cx);
+                                          })),
+                                  )
+                          }))
+                          .child(
+                              Button::new(#add_id)
+                                  .small()
+                                  .icon(IconName::Plus)
+                                  .on_click(cx.listener(|this, _, window, cx| {
+                                      this.#push_handler_ident(window, cx);
+                                  })),
+                          )
+                  )
+                  #errors
+                  #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        None
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let fields_path = component.fields_path();
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let form_components_struct_ident = component.struct_form_components_path();
+        let element_ty = field
+            .element_ty()
+            .expect("Expected List field to record its element type");
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let scoped_name = component.scoped_name(&field_var_name_str);
+        let fn_ident = |name: String| syn::parse_str::<syn::Ident>(&name).unwrap();
+        let push_fn_ident = fn_ident(format!("{}_push", field_var_name_str));
+        let remove_fn_ident = fn_ident(format!("{}_remove", field_var_name_str));
+        let move_fn_ident = fn_ident(format!("{}_move", field_var_name_str));
+        let push_handler_ident = fn_ident(format!("push_{}_row", scoped_name));
+        let remove_handler_ident = fn_ident(format!("remove_{}_row", scoped_name));
+        let move_handler_ident = fn_ident(format!("move_{}_row", scoped_name));
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+        let values = if field.optional {
+            quote! { self.#data_path.#field_name_ident.get_or_insert_default() }
+        } else {
+            quote! { self.#data_path.#field_name_ident }
+        };
+        let rows = quote! { self.#fields_path.#field_var_name_ident };
+
+        let (call_groups, row_handlers, push_row) = match list_item(field) {
+            ListItem::Input | ListItem::NumberInput => {
+                let event_handler_ident = fn_ident(format!("on_{}_row_event", scoped_name));
+                let values_from_rows = if let ListItem::Input = list_item(field) {
+                    quote! {
+                        .map(|row| row.read(cx).value().to_string().into())
+                    }
+                } else {
+                    quote! {
+                        .filter_map(|row| row.read(cx).value().parse::<#element_ty>().ok())
+                    }
+                };
+                let values_assign = if field.optional {
+                    quote! { Some(values) }
+                } else {
+                    quote! { values }
+                };
+                // any edit rebuilds the whole list, so rows never need to know their index
+                let event_handler = quote! {
+                    fn #event_handler_ident(
+                        &mut self,
+                        _state: &Entity<InputState>,
+                        event: &InputEvent,
+                        _window: &mut Window,
+                        cx: &mut Context<Self>,
+                    ) {
+                        if let InputEvent::Change = event {
+                            let values = #rows
+                                .iter()
+                                #values_from_rows
+                                .collect();
+                            self.#data_path.#field_name_ident = #values_assign;
+                        }
+                    }
+                };
+                let push_row = quote! {
+                    let value = <#element_ty>::default();
+                    let row = #form_components_struct_ident::#push_fn_ident(&mut #rows, window, cx);
+                    row.update(cx, |state, cx| state.set_value(value.to_string(), window, cx));
+                    let subscription = cx.subscribe_in(row, window, Self::#event_handler_ident);
+                    self._subscriptions.push(subscription);
+                    #values.push(value);
+                };
+                (
+                    vec![quote! {
+                        #local_prefix #field_var_name_ident
+                            .iter()
+                            .map(|row| cx.subscribe_in(row, window, Self::#event_handler_ident))
+                    }],
+                    vec![event_handler],
+                    push_row,
+                )
+            },
+            ListItem::Nested => {
+                let shape = nested_shape(field);
+                let row_component = component.list_row(shape, field);
+                let rows_path = quote! { #fields_path.#field_var_name_ident };
+                let subscription = shape
+                    .components
+                    .iter()
+                    .filter_map(|row_field| {
+                        field_generator_for(row_field)
+                            .generate_subscription(row_field, &row_component)
+                    })
+                    .fold(GeneratedSubscription::default(), |mut acc, sub| {
+                        acc.calls.extend(sub.calls);
+                        acc.handlers.extend(sub.handlers);
+                        acc
+                    });
+                let row_calls: Vec<TokenStream> = subscription
+                    .calls
+                    .iter()
+                    .map(|call| row_subscription_call(call, &rows_path))
+                    .collect();
+                let row_handlers = subscription.handlers.iter().map(row_handler).collect();
+                let push_row = quote! {
+                    let row = #form_components_struct_ident::#push_fn_ident(&mut #rows, window, cx);
+                    let subscriptions = [#(#row_calls),*];
+                    self._subscriptions.extend(subscriptions);
+                    #values.push(<#element_ty>::default().into());
+                };
+                let call_groups = if row_calls.is_empty() {
+                    vec![]
+                } else {
+                    vec![quote! {
+                        #local_prefix #field_var_name_ident
+                            .iter()
+                            .flat_map(|row| [#(#row_calls),*])
+                    }]
+                };
+                (call_groups, row_handlers, push_row)
+            },
+        };
+
+        let push_handler = quote! {
+            fn #push_handler_ident(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+                #push_row
+                cx.notify();
+            }
+        };
+
+        let remove_handler = quote! {
+            fn #remove_handler_ident(&mut self, index: usize, cx: &mut Context<Self>) {
+                if #form_components_struct_ident::#remove_fn_ident(&mut #rows, index).is_some() {
+                    #values.remove(index);
+                    cx.notify();
+                }
+            }
+        };
+
+        let move_handler = quote! {
+            fn #move_handler_ident(&mut self, from: usize, to: usize, cx: &mut Context<Self>) {
+                if from == to || !#form_components_struct_ident::#move_fn_ident(&mut #rows, from, to) {
+                    return;
+                }
+                let values = &mut #values;
+                let value = values.remove(from);
+                values.insert(to, value);
+                cx.notify();
+            }
+        };
+
+        let mut handlers = row_handlers;
+        handlers.extend([push_handler, remove_handler, move_handler]);
+
+        Some(GeneratedSubscription {
+            call_groups,
+            handlers,
+            ..Default::default()
+        })
+    }
+}
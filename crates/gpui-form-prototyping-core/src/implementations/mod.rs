@@ -1,25 +1,41 @@
 pub mod checkbox;
+pub mod color_picker;
 pub mod date_picker;
+pub mod date_range_picker;
+pub mod datetime_picker;
 pub mod dropdown;
 pub mod input;
+pub mod list;
+pub mod multi_select;
+pub mod nested;
 pub mod number_input;
+pub mod otp;
+pub mod radio_group;
+pub mod slider;
 pub mod switch;
+pub mod textarea;
+pub mod time_picker;
+pub mod variant;
+pub mod variant_selector;
 
 use crate::code_gen::ShapeIdentities;
 
 use gpui_form_core::registry::FieldVariant;
-use heck::ToSnakeCase as _;
+use heck::{ToPascalCase as _, ToSnakeCase as _};
 use proc_macro2::TokenStream;
+use quote::quote;
 
 #[derive(Default)]
 pub struct GeneratedSubscription {
     pub calls: Vec<TokenStream>,
+    /// Iterators of subscriptions, for states only known once the form is built (list rows).
+    pub call_groups: Vec<TokenStream>,
     pub handlers: Vec<TokenStream>,
 }
 
 impl GeneratedSubscription {
     pub fn is_empty(&self) -> bool {
-        self.calls.is_empty() && self.handlers.is_empty()
+        self.calls.is_empty() && self.call_groups.is_empty() && self.handlers.is_empty()
     }
 }
 
@@ -71,6 +87,20 @@ pub trait ComponentShape {
 
 pub trait ComponentIdentities {
     fn struct_name(&self) -> &'static str;
+    /// Arguments of the registered instantiation when the struct is generic.
+    fn generic_args(&self) -> Option<&'static str> {
+        None
+    }
+    /// `<u32>` for a shape registered as `Settings<u32>`, nothing for a non generic one.
+    fn generic_args_tokens(&self) -> TokenStream {
+        match self.generic_args() {
+            Some(generic_args) => {
+                let generic_args: TokenStream = generic_args.parse().unwrap();
+                quote! { <#generic_args> }
+            },
+            None => TokenStream::new(),
+        }
+    }
     fn struct_name_ident(&self) -> syn::Ident {
         syn::parse_str::<syn::Ident>(self.struct_name()).unwrap()
     }
@@ -82,6 +112,16 @@ pub trait ComponentIdentities {
         let str_repr = format!("{}FormComponents", self.struct_name());
         syn::parse_str::<syn::Ident>(&str_repr).unwrap()
     }
+    /// `XFormComponents`, turbofished with the generic arguments if any.
+    fn struct_form_components_path(&self) -> TokenStream {
+        let form_components_ident = self.struct_form_components_ident();
+        let generic_args = self.generic_args_tokens();
+        if generic_args.is_empty() {
+            quote! { #form_components_ident }
+        } else {
+            quote! { #form_components_ident::#generic_args }
+        }
+    }
     fn struct_form_fields_ident(&self) -> syn::Ident {
         let str_repr = format!("{}FormFields", self.struct_name());
         syn::parse_str::<syn::Ident>(&str_repr).unwrap()
@@ -97,6 +137,32 @@ pub trait ComponentIdentities {
         let str_repr = format!("{}DescriptionFtl", self.struct_name());
         syn::parse_str::<syn::Ident>(&str_repr).unwrap()
     }
+    /// Label of `field`: its `label` attribute if set, its `XLabelFtl` key otherwise.
+    fn field_label(&self, field: &FieldVariant) -> TokenStream {
+        match field.label {
+            Some(label) => quote! { #label },
+            None => {
+                let ftl_label_ident = self.ftl_label_ident();
+                let variant_ident =
+                    syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
+                quote! { #ftl_label_ident::#variant_ident.to_string() }
+            },
+        }
+    }
+    /// `.description(..)` call for `field`, from its `description` attribute if set, or
+    /// its `XDescriptionFtl` key unless the field labels itself without Fluent.
+    fn field_description(&self, field: &FieldVariant) -> TokenStream {
+        match (field.description, field.label) {
+            (Some(description), _) => quote! { .description(#description) },
+            (None, Some(_)) => TokenStream::new(),
+            (None, None) => {
+                let ftl_description_ident = self.ftl_description_ident();
+                let variant_ident =
+                    syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
+                quote! { .description(#ftl_description_ident::#variant_ident.to_string()) }
+            },
+        }
+    }
 }
 
 impl ComponentIdentities for FieldVariant {
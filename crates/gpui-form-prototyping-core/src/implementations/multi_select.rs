@@ -0,0 +1,260 @@
+use gpui_form_core::registry::FieldVariant;
+use heck::ToKebabCase as _;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct MultiSelectCodeGenerator;
+
+/// Statements copying the values selected in the value holder of `receiver` to the entity
+/// holding them in its fields, and offering the other items in the dropdown.
+fn sync_selected_tokens(
+    receiver: TokenStream,
+    field: &FieldVariant,
+    component: &ShapeIdentities,
+) -> TokenStream {
+    let fields_path = component.fields_path();
+    let data_path = component.data_path();
+
+    let element_ty = field
+        .element_ty()
+        .expect("Expected MultiSelect field to record its element type");
+    let suffix = field.component_suffix();
+    let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+    let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+    let selected_ident =
+        syn::parse_str::<syn::Ident>(&format!("{}_selected", field_var_name_str)).unwrap();
+    let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+    let selected = if !field.optional {
+        quote! { #receiver.#data_path.#field_name_ident.iter() }
+    } else {
+        quote! { #receiver.#data_path.#field_name_ident.iter().flatten() }
+    };
+
+    quote! {
+        use strum::IntoEnumIterator as _;
+        let selected = #selected.cloned().collect::<Vec<#element_ty>>();
+        let items = <#element_ty>::iter()
+            .filter(|item| !selected.contains(item))
+            .collect::<Vec<#element_ty>>();
+        #receiver.#fields_path.#field_var_name_ident.update(cx, |state, cx| {
+            state.set_items(items.into(), window, cx);
+            state.set_selected_index(None, window, cx);
+        });
+        #receiver.#fields_path.#selected_ident.update(cx, |values, cx| {
+            *values = selected;
+            cx.notify();
+        });
+    }
+}
+
+impl FieldCodeGenerator for MultiSelectCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        let selected_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}_selected", field.field_name, suffix))
+                .unwrap();
+
+        Some(quote! {
+            let #var_name_ident =
+                cx.new(|cx| #form_components_struct_ident::#fn_name_ident(window, cx));
+            let #selected_ident = cx.new(|_| Vec::new());
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let selected_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_selected", field_var_name_str)).unwrap();
+
+        Some(quote! { #field_var_name_ident, #selected_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+        let data_path = component.data_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+        let remove_button_id = format!(
+            "{}-remove",
+            component
+                .scoped_name(&field_in_struct_name_str)
+                .to_kebab_case()
+        );
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let (selected, remove) = if !field.optional {
+            (
+                quote! { self.#data_path.#field_name_ident.iter() },
+                quote! { this.#data_path.#field_name_ident.retain(|item| item != &value); },
+            )
+        } else {
+            (
+                quote! { self.#data_path.#field_name_ident.iter().flatten() },
+                quote! {
+                    if let Some(values) = &mut this.#data_path.#field_name_ident {
+                        values.retain(|item| item != &value);
+                    }
+                },
+            )
+        };
+
+        let sync_selected = sync_selected_tokens(quote! { this }, field, component);
+
+        // selected values are shown as removable chips above the dropdown used to add the others
+        quote! {
+            .child(
+                form_field()
+                  .label(#label)
+                  #description
+                  .child(
+                      v_flex()
+                          .gap_2()
+                          .child(
+                              h_flex()
+                                  .flex_wrap()
+                                  .gap_1()
+                                  .children(#selected.cloned().enumerate().map(|(ix, value)| {
+                                      Tag::secondary().child(
+                                          h_flex()
+                                              .gap_1()
+                                              .child(value.title())
+                                              .child(
+                                                  Button::new((#remove_button_id, ix))
+                                                      .ghost()
+                                                      .xsmall()
+                                                      .icon(IconName::Close)
+                                                      .on_click(cx.listener(move |this, _, window, cx| {
+                                                          #remove
+                                                          #sync_selected
+                                                          cx.notify();
+                                                      })),
+                                              ),
+                                      )
+                                  })),
+                          )
+                          .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                  )
+                  #errors
+                  #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let x = quote! {
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let element_ty = field
+            .element_ty()
+            .expect("Expected MultiSelect field to record its element type");
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let event_handler_fn_name = format!(
+            "on_{}_multi_select_event",
+            component.scoped_name(field.field_name)
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let values = if !field.optional {
+            quote! { &mut self.#data_path.#field_name_ident }
+        } else {
+            quote! { self.#data_path.#field_name_ident.get_or_insert_default() }
+        };
+
+        let sync_selected = sync_selected_tokens(quote! { self }, field, component);
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                _this: &Entity<DropdownState<SearchableVec<#element_ty>>>,
+                event: &DropdownEvent<SearchableVec<#element_ty>>,
+                window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    DropdownEvent::Confirm(value) => {
+                        if let Some(value) = value {
+                            let values = #values;
+                            if !values.contains(value) {
+                                values.extend([value.clone()]);
+                            }
+                            #sync_selected
+                            cx.notify();
+                        }
+                    },
+                }
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler],
+            ..Default::default()
+        })
+    }
+}
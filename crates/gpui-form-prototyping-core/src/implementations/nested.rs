@@ -0,0 +1,133 @@
+use gpui_form_core::registry::{FieldVariant, GpuiFormShape};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    code_gen::{ShapeIdentities, field_generator_for},
+    implementations::ComponentIdentities as _,
+};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct NestedCodeGenerator;
+
+pub(crate) fn nested_shape(field: &FieldVariant) -> &'static GpuiFormShape {
+    field.nested_shape().unwrap_or_else(|| {
+        panic!(
+            "No GpuiFormShape registered for `{}`, the type of nested field `{}`",
+            field.field_type, field.field_name
+        )
+    })
+}
+
+impl FieldCodeGenerator for NestedCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        Some(quote! {
+            let #var_name_ident = #form_components_struct_ident::#var_name_ident(window, cx);
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let shape = nested_shape(field);
+        let nested = component.nested(shape, field);
+
+        let label = component.field_label(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let children: TokenStream = shape
+            .components
+            .iter()
+            .map(|nested_field| {
+                field_generator_for(nested_field).generate_render_child(nested_field, &nested)
+            })
+            .collect();
+
+        quote! {
+            .child(
+                #component_gpui_type::new()
+                    .title(#label)
+                    .child(
+                        v_form()
+                            #children
+                    )
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let shape = nested_shape(field);
+        let nested = component.nested(shape, field);
+
+        let x: TokenStream = shape
+            .components
+            .iter()
+            .filter_map(|nested_field| {
+                field_generator_for(nested_field).generate_focusable_cycle(nested_field, &nested)
+            })
+            .collect();
+
+        if x.is_empty() { None } else { Some(x) }
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let shape = nested_shape(field);
+        let nested = component.nested(shape, field);
+
+        let subscription = shape
+            .components
+            .iter()
+            .filter_map(|nested_field| {
+                field_generator_for(nested_field).generate_subscription(nested_field, &nested)
+            })
+            .fold(GeneratedSubscription::default(), |mut acc, sub| {
+                acc.calls.extend(sub.calls);
+                acc.call_groups.extend(sub.call_groups);
+                acc.handlers.extend(sub.handlers);
+                acc
+            });
+
+        if subscription.is_empty() {
+            None
+        } else {
+            Some(subscription)
+        }
+    }
+}
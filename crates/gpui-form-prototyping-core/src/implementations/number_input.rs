@@ -1,5 +1,4 @@
 use gpui_form_core::registry::FieldVariant;
-use heck::ToPascalCase as _;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -15,8 +14,8 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let form_components_struct_ident = component.struct_form_components_ident();
-        let suffix = field.behaviour.to_string();
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
         let var_name_ident =
             syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
         let fn_name_ident =
@@ -33,7 +32,7 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
         field: &FieldVariant,
         _component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
 
         let state_initializer = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
@@ -48,11 +47,13 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
         field: &FieldVariant,
         component: &ShapeIdentities,
     ) -> TokenStream {
-        let ftl_label_ident = component.ftl_label_ident();
-        let ftl_description_ident = component.ftl_description_ident();
-        let field_name_pascal_case_ident =
-            syn::parse_str::<syn::Ident>(&field.field_name.to_pascal_case()).unwrap();
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
 
         let component_gpui_type = field.behaviour.as_component_ident();
 
@@ -63,9 +64,11 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
         quote! {
             .child(
                 form_field()
-                    .label(#ftl_label_ident::#field_name_pascal_case_ident.to_string())
-                    .description(#ftl_description_ident::#field_name_pascal_case_ident.to_string())
-                    .child(#component_gpui_type::new(&self.fields.#field_in_struct_name_ident))
+                    .label(#label)
+                    #description
+                    .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                    #errors
+                    #modified
             )
         }
     }
@@ -73,13 +76,15 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
     fn generate_focusable_cycle(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<TokenStream> {
-        let suffix = field.behaviour.to_string();
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
         let x = quote! {
-          self.fields.#field_var_name_ident.focus_handle(cx),
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
         };
         Some(x)
     }
@@ -87,23 +92,29 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
     fn generate_subscription(
         &self,
         field: &FieldVariant,
-        _component: &ShapeIdentities,
+        component: &ShapeIdentities,
     ) -> Option<GeneratedSubscription> {
-        let suffix = field.behaviour.to_string();
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
         let field_var_name_str = format!("{}_{}", field.field_name, suffix);
         let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
 
-        let on_input_event_handler_fn_name = format!("on_{}_input_event", field.field_name);
+        let on_input_event_handler_fn_name =
+            format!("on_{}_input_event", component.scoped_name(field.field_name));
         let on_input_event_handler_fn_name_ident =
             syn::parse_str::<syn::Ident>(&on_input_event_handler_fn_name).unwrap();
-        let on_number_input_event_handler_fn_name =
-            format!("on_{}_number_input_event", field.field_name);
+        let on_number_input_event_handler_fn_name = format!(
+            "on_{}_number_input_event",
+            component.scoped_name(field.field_name)
+        );
         let on_number_input_event_handler_fn_name_ident =
             syn::parse_str::<syn::Ident>(&on_number_input_event_handler_fn_name).unwrap();
 
         let calls = vec![
-            quote! { cx.subscribe_in(&#field_var_name_ident, window, Self::#on_input_event_handler_fn_name_ident) },
-            quote! { cx.subscribe_in(&#field_var_name_ident, window, Self::#on_number_input_event_handler_fn_name_ident) },
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#on_input_event_handler_fn_name_ident) },
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#on_number_input_event_handler_fn_name_ident) },
         ];
 
         let mut handlers = vec![];
@@ -124,7 +135,7 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
                     InputEvent::Change => {
                         let text = state.read(_cx).value();
                         if let Ok(value) = text.parse::<#field_type_path>() {
-                            self.current_data.#field_name_ident = value.into();
+                            self.#data_path.#field_name_ident = value.into();
                         }
                     }
                     _ => {}
@@ -139,36 +150,36 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
             // f*
             (
                 quote! {
-                    let new_value = self.current_data.#field_name_ident - 1 as #field_type_ty;
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident - 1 as #field_type_ty;
+                    self.#data_path.#field_name_ident = new_value;
                 },
                 quote! {
-                    let new_value = self.current_data.#field_name_ident + 1 as #field_type_ty;
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident + 1 as #field_type_ty;
+                    self.#data_path.#field_name_ident = new_value;
                 },
             )
         } else if field.field_type.starts_with('u') || field.field_type.starts_with('i') {
             // i*, u*,
             (
                 quote! {
-                    let new_value = self.current_data.#field_name_ident.saturating_sub(1 as #field_type_ty);
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident.saturating_sub(1 as #field_type_ty);
+                    self.#data_path.#field_name_ident = new_value;
                 },
                 quote! {
-                    let new_value = self.current_data.#field_name_ident.saturating_add(1 as #field_type_ty);
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident.saturating_add(1 as #field_type_ty);
+                    self.#data_path.#field_name_ident = new_value;
                 },
             )
         } else {
             // external types (assuming they impl `impl_saturating!`)
             (
                 quote! {
-                    let new_value = self.current_data.#field_name_ident.saturating_sub(#field_type_ty::from(1));
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident.saturating_sub(#field_type_ty::from(1));
+                    self.#data_path.#field_name_ident = new_value;
                 },
                 quote! {
-                    let new_value = self.current_data.#field_name_ident.saturating_add(#field_type_ty::from(1));
-                    self.current_data.#field_name_ident = new_value;
+                    let new_value = self.#data_path.#field_name_ident.saturating_add(#field_type_ty::from(1));
+                    self.#data_path.#field_name_ident = new_value;
                 },
             )
         };
@@ -186,13 +197,13 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
                         StepAction::Decrement => {
                             #decrement_logic
                             this.update(cx, |input, cx| {
-                                input.set_value(self.current_data.#field_name_ident.to_string(), window, cx);
+                                input.set_value(self.#data_path.#field_name_ident.to_string(), window, cx);
                             });
                         }
                         StepAction::Increment => {
                             #increment_logic
                             this.update(cx, |input, cx| {
-                                input.set_value(self.current_data.#field_name_ident.to_string(), window, cx);
+                                input.set_value(self.#data_path.#field_name_ident.to_string(), window, cx);
                             });
                         }
                     },
@@ -201,6 +212,10 @@ impl FieldCodeGenerator for NumberInputCodeGenerator {
         };
         handlers.push(on_number_input_event_handler);
 
-        Some(GeneratedSubscription { calls, handlers })
+        Some(GeneratedSubscription {
+            calls,
+            handlers,
+            ..Default::default()
+        })
     }
 }
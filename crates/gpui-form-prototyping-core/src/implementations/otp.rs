@@ -0,0 +1,162 @@
+use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct OtpCodeGenerator;
+
+impl FieldCodeGenerator for OtpCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        Some(quote! {
+            let #var_name_ident =
+                cx.new(|cx| #form_components_struct_ident::#fn_name_ident(window, cx));
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+
+        quote! {
+            .child(
+                form_field()
+                    .label(#label)
+                    #description
+                    .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let x = quote! {
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let length = if let ComponentsBehaviour::Otp(otp_config) = &field.behaviour {
+            otp_config.length
+        } else {
+            panic!("Expected Otp behaviour")
+        };
+
+        let event_handler_fn_name =
+            format!("on_{}_otp_event", component.scoped_name(field.field_name));
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+        let complete_handler_fn_name = format!(
+            "on_{}_otp_complete",
+            component.scoped_name(field.field_name)
+        );
+        let complete_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&complete_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                state: &Entity<OtpState>,
+                event: &InputEvent,
+                window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    InputEvent::Change => {
+                      let code = state.read(cx).value().clone();
+                      self.#data_path.#field_name_ident = code.to_string().into();
+                      if code.chars().count() == #length {
+                          self.#complete_handler_fn_name_ident(code, window, cx);
+                      }
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        // called once every digit has been entered, fill in to e.g. auto-submit the form
+        let complete_handler = quote! {
+            fn #complete_handler_fn_name_ident(
+                &mut self,
+                _code: SharedString,
+                _window: &mut Window,
+                _cx: &mut Context<Self>,
+            ) {
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler, complete_handler],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use gpui_form_core::{
+    components::{ComponentsBehaviour, Orientation},
+    registry::FieldVariant,
+};
+use heck::ToKebabCase as _;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct RadioGroupCodeGenerator;
+
+impl FieldCodeGenerator for RadioGroupCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        None
+    }
+
+    fn generate_field_initializers(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        None
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let data_path = component.data_path();
+
+        let orientation =
+            if let ComponentsBehaviour::RadioGroup(radio_group_config) = &field.behaviour {
+                radio_group_config.orientation
+            } else {
+                panic!("Expected RadioGroup behaviour")
+            };
+        let form_components_struct_ident = component.struct_form_components_path();
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let radio_group_id_str = component
+            .scoped_name(&format!("{}_{}", field.field_name, suffix))
+            .to_kebab_case();
+
+        let constructor = match orientation {
+            Orientation::Horizontal => quote! { horizontal },
+            Orientation::Vertical => quote! { vertical },
+        };
+
+        quote! {
+            .child(
+                form_field()
+                    .label(#label)
+                    #description
+                    .child(#component_gpui_type::#constructor(#radio_group_id_str)
+                    .children(
+                        #form_components_struct_ident::#fn_name_ident()
+                            .iter()
+                            .enumerate()
+                            .map(|(ix, item)| Radio::new(ix).label(item.title())),
+                    )
+                    .selected_index(
+                        #form_components_struct_ident::#fn_name_ident()
+                            .iter()
+                            .position(|item| *item == self.#data_path.#field_name_ident),
+                    )
+                    .on_change(cx.listener(move |v, ix: &usize, _, cx| {
+                        if let Some(item) = #form_components_struct_ident::#fn_name_ident().get(*ix) {
+                            v.#data_path.#field_name_ident = item.clone();
+                            cx.notify();
+                        }
+                    })),
+                )
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        None
+    }
+
+    fn generate_subscription(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        None
+    }
+}
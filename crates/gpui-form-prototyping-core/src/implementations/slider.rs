@@ -0,0 +1,137 @@
+use gpui_form_core::registry::FieldVariant;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct SliderCodeGenerator;
+
+impl FieldCodeGenerator for SliderCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        Some(quote! {
+            let #var_name_ident =
+                cx.new(|cx| #form_components_struct_ident::#fn_name_ident(window, cx));
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+
+        quote! {
+            .child(
+                form_field()
+                  .label(#label)
+                  #description
+                  .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+                  #errors
+                  #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        _field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        None
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let event_handler_fn_name = format!(
+            "on_{}_slider_event",
+            component.scoped_name(field.field_name)
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        // `SliderValue` is always `f32`, other numeric types need a cast
+        let value = if field.field_type == "f32" {
+            quote! { value.start() }
+        } else {
+            let field_type_ty = syn::parse_str::<syn::Type>(field.field_type).unwrap();
+            quote! { value.start() as #field_type_ty }
+        };
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                _this: &Entity<SliderState>,
+                event: &SliderEvent,
+                _window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    SliderEvent::Change(value) => {
+                        self.#data_path.#field_name_ident = #value;
+                        cx.notify();
+                    }
+                }
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+/// Approximate height of a single text row, used to size non auto-growing textareas.
+const ROW_HEIGHT: f32 = 24.;
+
+pub struct TextareaCodeGenerator;
+
+impl FieldCodeGenerator for TextareaCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let fn_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+
+        Some(quote! {
+            let #var_name_ident =
+                cx.new(|cx| #form_components_struct_ident::#fn_name_ident(window, cx));
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+
+        let height = if let ComponentsBehaviour::Textarea(textarea_config) = &field.behaviour {
+            if textarea_config.auto_grow {
+                quote! {}
+            } else {
+                let height = textarea_config.rows as f32 * ROW_HEIGHT;
+                quote! { .h(px(#height)) }
+            }
+        } else {
+            panic!("Expected Textarea behaviour")
+        };
+
+        quote! {
+            .child(
+                form_field()
+                    .label(#label)
+                    #description
+                    .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident)#height)
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        let x = quote! {
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let event_handler_fn_name = format!(
+            "on_{}_textarea_event",
+            component.scoped_name(field.field_name)
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                state: &Entity<InputState>,
+                event: &InputEvent,
+                _window: &mut Window,
+                _cx: &mut Context<Self>,
+            ) {
+                match event {
+                    InputEvent::Change => {
+                      let text = state.read(_cx).value();
+                      self.#data_path.#field_name_ident = text.to_owned().into();
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,234 @@
+use gpui_form_core::{components::ComponentsBehaviour, registry::FieldVariant};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct TimePickerCodeGenerator;
+
+/// Idents of the hour, minute and optional second inputs backing a time or datetime picker.
+pub(super) fn time_part_idents(field: &FieldVariant, seconds: bool) -> Vec<syn::Ident> {
+    let suffix = field.component_suffix();
+    let mut parts = vec!["hour", "minute"];
+    if seconds {
+        parts.push("second");
+    }
+    parts
+        .into_iter()
+        .map(|part| {
+            syn::parse_str::<syn::Ident>(&format!("{}_{}_{}", field.field_name, suffix, part))
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Binds `time` from the part inputs, returning early while they don't form a valid time.
+pub(super) fn time_value_tokens(
+    component: &ShapeIdentities,
+    part_idents: &[syn::Ident],
+) -> TokenStream {
+    let fields_path = component.fields_path();
+    let hour = &part_idents[0];
+    let minute = &part_idents[1];
+    let second = match part_idents.get(2) {
+        Some(second) => quote! { part(&self.#fields_path.#second) },
+        None => quote! { 0 },
+    };
+
+    #[cfg(all(feature = "jiff", not(feature = "chrono")))]
+    let time = quote! {
+        let part = |state: &Entity<InputState>| {
+            state.read(cx).value().trim().parse::<i8>().unwrap_or_default()
+        };
+        let Ok(time) = jiff::civil::Time::new(
+            part(&self.#fields_path.#hour),
+            part(&self.#fields_path.#minute),
+            #second,
+            0,
+        ) else {
+            return;
+        };
+    };
+
+    #[cfg(all(feature = "chrono", not(feature = "jiff")))]
+    let time = quote! {
+        let part = |state: &Entity<InputState>| {
+            state.read(cx).value().trim().parse::<u32>().unwrap_or_default()
+        };
+        let Some(time) = chrono::NaiveTime::from_hms_opt(
+            part(&self.#fields_path.#hour),
+            part(&self.#fields_path.#minute),
+            #second,
+        ) else {
+            return;
+        };
+    };
+
+    time
+}
+
+pub(super) fn time_part_children(
+    component: &ShapeIdentities,
+    part_idents: &[syn::Ident],
+) -> TokenStream {
+    let fields_path = component.fields_path();
+    let children = part_idents.iter().enumerate().map(|(ix, part_ident)| {
+        let separator = if ix > 0 {
+            quote! { .child(":") }
+        } else {
+            quote! {}
+        };
+        quote! {
+            #separator
+            .child(TextInput::new(&self.#fields_path.#part_ident).w(px(56.)))
+        }
+    });
+    quote! { #(#children)* }
+}
+
+fn seconds(field: &FieldVariant) -> bool {
+    match &field.behaviour {
+        ComponentsBehaviour::TimePicker(time_config) => time_config.seconds,
+        _ => panic!("Expected TimePicker behaviour"),
+    }
+}
+
+impl FieldCodeGenerator for TimePickerCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let form_components_struct_ident = component.struct_form_components_path();
+        let calls = time_part_idents(field, seconds(field))
+            .into_iter()
+            .map(|part_ident| {
+                quote! {
+                    let #part_ident =
+                        cx.new(|cx| #form_components_struct_ident::#part_ident(window, cx));
+                }
+            });
+
+        Some(quote! { #(#calls)* })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let part_idents = time_part_idents(field, seconds(field));
+
+        Some(quote! { #(#part_idents,)* })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let label = component.field_label(field);
+        let description = component.field_description(field);
+        let errors = component.field_errors(field);
+        let modified = component.field_modified(field);
+
+        let children = time_part_children(component, &time_part_idents(field, seconds(field)));
+
+        quote! {
+            .child(
+                form_field()
+                    .label(#label)
+                    #description
+                    .child(h_flex().gap_1() #children)
+                    #errors
+                    #modified
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let part_idents = time_part_idents(field, seconds(field));
+        let x = quote! {
+          #(self.#fields_path.#part_idents.focus_handle(cx),)*
+        };
+        Some(x)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let suffix = field.component_suffix();
+        let part_idents = time_part_idents(field, seconds(field));
+
+        let event_handler_fn_name = format!(
+            "on_{}_{}_event",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+        let sync_fn_name = format!(
+            "sync_{}_{}",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let sync_fn_name_ident = syn::parse_str::<syn::Ident>(&sync_fn_name).unwrap();
+
+        let calls = part_idents
+            .iter()
+            .map(|part_ident| {
+                quote! { cx.subscribe_in(&#local_prefix #part_ident, window, Self::#event_handler_fn_name_ident) }
+            })
+            .collect();
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let time = time_value_tokens(component, &part_idents);
+        let value_assign = if !field.optional {
+            quote! { self.#data_path.#field_name_ident = time; }
+        } else {
+            quote! { self.#data_path.#field_name_ident = Some(time); }
+        };
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                _state: &Entity<InputState>,
+                event: &InputEvent,
+                _window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    InputEvent::Change => self.#sync_fn_name_ident(cx),
+                    _ => {}
+                }
+            }
+        };
+
+        let sync = quote! {
+            fn #sync_fn_name_ident(&mut self, cx: &mut Context<Self>) {
+                #time
+                #value_assign
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler, sync],
+            ..Default::default()
+        })
+    }
+}
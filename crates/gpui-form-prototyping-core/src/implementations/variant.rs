@@ -0,0 +1,89 @@
+use gpui_form_core::registry::FieldVariant;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    code_gen::{ShapeIdentities, field_generator_for},
+    implementations::ComponentIdentities as _,
+};
+
+use super::{
+    FieldCodeGenerator, GeneratedSubscription,
+    nested::{NestedCodeGenerator, nested_shape},
+};
+
+/// A variant's sub-form, built like a nested one and only rendered while the variant is selected.
+pub struct VariantCodeGenerator;
+
+impl FieldCodeGenerator for VariantCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        NestedCodeGenerator.generate_cx_new_call(field, component)
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        NestedCodeGenerator.generate_field_initializers(field, component)
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let shape = nested_shape(field);
+        let nested = component.nested(shape, field);
+        let data_path = component.data_path();
+
+        let variant_enum_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}FormVariant", component.struct_name()))
+                .unwrap();
+        let variant_ident = syn::parse_str::<syn::Ident>(field.field_type).unwrap();
+        let variant_title = field.field_type;
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let children: TokenStream = shape
+            .components
+            .iter()
+            .map(|nested_field| {
+                field_generator_for(nested_field).generate_render_child(nested_field, &nested)
+            })
+            .collect();
+
+        quote! {
+            .when(self.#data_path.variant == #variant_enum_ident::#variant_ident, |this| {
+                this.child(
+                    #component_gpui_type::new()
+                        .title(#variant_title)
+                        .child(
+                            v_form()
+                                #children
+                        )
+                )
+            })
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        NestedCodeGenerator.generate_focusable_cycle(field, component)
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        NestedCodeGenerator.generate_subscription(field, component)
+    }
+}
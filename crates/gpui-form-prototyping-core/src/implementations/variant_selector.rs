@@ -0,0 +1,134 @@
+use gpui_form_core::registry::FieldVariant;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{code_gen::ShapeIdentities, implementations::ComponentIdentities as _};
+
+use super::{FieldCodeGenerator, GeneratedSubscription};
+
+pub struct VariantSelectorCodeGenerator;
+
+impl FieldCodeGenerator for VariantSelectorCodeGenerator {
+    fn generate_cx_new_call(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let data_path = component.data_path();
+        let form_components_struct_ident = component.struct_form_components_path();
+        let suffix = field.component_suffix();
+        let var_name_ident =
+            syn::parse_str::<syn::Ident>(&format!("{}_{}", field.field_name, suffix)).unwrap();
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        Some(quote! {
+            let #var_name_ident = cx.new(|cx| {
+                let mut state = #form_components_struct_ident::#var_name_ident(window, cx);
+                state.set_selected_value(&#data_path.#field_name_ident, window, cx);
+                state
+            });
+        })
+    }
+
+    fn generate_field_initializers(
+        &self,
+        field: &FieldVariant,
+        _component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        Some(quote! { #field_var_name_ident, })
+    }
+
+    fn generate_render_child(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> TokenStream {
+        let fields_path = component.fields_path();
+        let suffix = field.component_suffix();
+
+        let component_gpui_type = field.behaviour.as_component_ident();
+
+        let field_in_struct_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_in_struct_name_ident =
+            syn::parse_str::<syn::Ident>(&field_in_struct_name_str).unwrap();
+
+        quote! {
+            .child(
+                form_field()
+                  .child(#component_gpui_type::new(&self.#fields_path.#field_in_struct_name_ident))
+            )
+        }
+    }
+
+    fn generate_focusable_cycle(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<TokenStream> {
+        let fields_path = component.fields_path();
+
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+        Some(quote! {
+          self.#fields_path.#field_var_name_ident.focus_handle(cx),
+        })
+    }
+
+    fn generate_subscription(
+        &self,
+        field: &FieldVariant,
+        component: &ShapeIdentities,
+    ) -> Option<GeneratedSubscription> {
+        let data_path = component.data_path();
+        let local_prefix = component.local_prefix();
+
+        let struct_name_ident = field.struct_name_ident();
+        let suffix = field.component_suffix();
+        let field_var_name_str = format!("{}_{}", field.field_name, suffix);
+        let field_var_name_ident = syn::parse_str::<syn::Ident>(&field_var_name_str).unwrap();
+
+        let event_handler_fn_name = format!(
+            "on_{}_{}_event",
+            component.scoped_name(field.field_name),
+            suffix
+        );
+        let event_handler_fn_name_ident =
+            syn::parse_str::<syn::Ident>(&event_handler_fn_name).unwrap();
+
+        let calls = vec![
+            quote! { cx.subscribe_in(&#local_prefix #field_var_name_ident, window, Self::#event_handler_fn_name_ident) },
+        ];
+
+        let field_name_ident = syn::parse_str::<syn::Ident>(field.field_name).unwrap();
+
+        let handler = quote! {
+            fn #event_handler_fn_name_ident(
+                &mut self,
+                _this: &Entity<DropdownState<Vec<#struct_name_ident>>>,
+                event: &DropdownEvent<Vec<#struct_name_ident>>,
+                _window: &mut Window,
+                cx: &mut Context<Self>,
+            ) {
+                match event {
+                    DropdownEvent::Confirm(value) => {
+                        if let Some(value) = value {
+                            self.#data_path.#field_name_ident = *value;
+                            cx.notify();
+                        }
+                    },
+                }
+            }
+        };
+
+        Some(GeneratedSubscription {
+            calls,
+            handlers: vec![handler],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use gpui::{App, Context, Entity, Subscription, Window};
+use gpui_component::input::{InputEvent, InputState, NumberInputEvent, StepAction};
+use gpui_form_core::components::ComponentsBehaviour;
+use num_regex::NumRegex;
+
+/// The component `#[gpui_form(infer)]` gives fields of a type it can't pick from the type's path
+/// alone, such as numbers and enums.
+///
+/// Every `NumRegex` type gets a number input, and `#[derive(DropdownItem)]` gives enums of unit
+/// variants a dropdown. Other types can implement it to be inferred as well.
+pub trait GpuiFormDefaultComponent {
+    /// State of the component, held in `XFormFields`.
+    type State: 'static;
+
+    /// The component, as recorded in the `GpuiFormShape`.
+    const BEHAVIOUR: ComponentsBehaviour;
+
+    /// Creates the state, see the constructors of `XFormComponents`.
+    fn build_state(window: &mut Window, cx: &mut Context<'_, Self::State>) -> Self::State;
+
+    /// Subscriptions writing the changes of `state` to the value `value` reaches, see
+    /// `XFormFields::subscribe`. Without them the value only changes through the render.
+    fn subscribe<S: 'static>(
+        _state: &Entity<Self::State>,
+        _window: &mut Window,
+        _cx: &mut Context<S>,
+        _value: impl Fn(&mut S) -> &mut Self + Copy + 'static,
+    ) -> Vec<Subscription> {
+        Vec::new()
+    }
+
+    /// Sets `state` to `value`, see `XFormFields::load`.
+    fn load(_state: &Entity<Self::State>, _value: &Self, _window: &mut Window, _cx: &mut App) {}
+
+    /// Writes `state` back to `value`, see `XFormFields::read`.
+    fn read(_state: &Entity<Self::State>, _value: &mut Self, _cx: &App) {}
+}
+
+impl<T: NumRegex + FromStr + Display + 'static> GpuiFormDefaultComponent for T {
+    type State = InputState;
+
+    const BEHAVIOUR: ComponentsBehaviour = ComponentsBehaviour::NumberInput;
+
+    fn build_state(window: &mut Window, cx: &mut Context<'_, InputState>) -> InputState {
+        InputState::new(window, cx).pattern(T::validation_regex().clone())
+    }
+
+    fn subscribe<S: 'static>(
+        state: &Entity<InputState>,
+        window: &mut Window,
+        cx: &mut Context<S>,
+        value: impl Fn(&mut S) -> &mut Self + Copy + 'static,
+    ) -> Vec<Subscription> {
+        vec![
+            cx.subscribe_in(
+                state,
+                window,
+                move |this, state, event: &InputEvent, _window, cx| {
+                    if let InputEvent::Change = event
+                        && let Ok(parsed) = state.read(cx).value().parse()
+                    {
+                        *value(this) = parsed;
+                    }
+                },
+            ),
+            cx.subscribe_in(
+                state,
+                window,
+                move |this, state, event: &NumberInputEvent, window, cx| match event {
+                    NumberInputEvent::Step(step_action) => {
+                        let stepped = match step_action {
+                            StepAction::Decrement => value(this).step_down(),
+                            StepAction::Increment => value(this).step_up(),
+                        };
+                        let text = stepped.to_string();
+                        *value(this) = stepped;
+                        state.update(cx, |input, cx| input.set_value(text, window, cx));
+                    },
+                },
+            ),
+        ]
+    }
+
+    fn load(state: &Entity<InputState>, value: &Self, window: &mut Window, cx: &mut App) {
+        let text = value.to_string();
+        state.update(cx, |input, cx| input.set_value(text, window, cx));
+    }
+
+    fn read(state: &Entity<InputState>, value: &mut Self, cx: &App) {
+        if let Ok(parsed) = state.read(cx).value().parse() {
+            *value = parsed;
+        }
+    }
+}
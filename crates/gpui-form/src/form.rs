@@ -0,0 +1,19 @@
+use gpui::{App, Window};
+use gpui_form_core::registry::GpuiFormShape;
+
+/// Implemented by every `#[derive(GpuiForm)]` type that can build its fields, tying it to its
+/// generated `XFormValueHolder` and `XFormFields`.
+///
+/// Generic structs implement it for each instantiation named in `#[gpui_form(register(..))]`.
+pub trait Form: Sized {
+    /// `XFormValueHolder`, the editable counterpart of the type.
+    type ValueHolder: From<Self> + Into<Self>;
+    /// `XFormFields`, the component states of the form.
+    type Fields;
+
+    /// Creates the component states of the form, see `XFormComponents::build_fields`.
+    fn build_fields(window: &mut Window, cx: &mut App) -> Self::Fields;
+
+    /// The shape of the form, the same one `inventory` registers.
+    fn shape() -> &'static GpuiFormShape;
+}
@@ -1,3 +1,15 @@
+pub mod time;
+
+mod default_component;
+
+pub use default_component::GpuiFormDefaultComponent;
+
+mod form;
+mod validation;
+
+pub use form::Form;
+pub use validation::{AsyncValidation, ValidationError};
+
 #[cfg(feature = "derive")]
 pub use gpui_form_derive::*;
 
@@ -5,4 +17,9 @@ pub use num_regex::*;
 
 pub use gpui_form_core as core;
 
+#[cfg(feature = "validate")]
+pub use garde;
+
+pub use regex;
+
 pub use unwrapped;
@@ -0,0 +1,208 @@
+//! Conversions between the values of date range, time and datetime fields and the states of
+//! their pickers, going through the text chrono and jiff types display and parse, so forms
+//! work with either.
+
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Time zone suffixes chrono and jiff parse as UTC, tried in turn for time zone aware values
+/// with no previous value to take the zone of.
+const UTC: [&str; 2] = [" UTC", "[UTC]"];
+
+/// Pattern of the hour, minute and second inputs of time and datetime pickers, letting in up
+/// to two digits.
+pub fn part_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r"^\d{0,2}$").expect("Invalid regex pattern"))
+}
+
+/// Splits a time displayed as `HH:MM:SS`, as chrono and jiff times are, into the values of the
+/// hour, minute and second inputs, leaving out fractions of a second and anything after them.
+pub fn time_parts(time: &str) -> Option<[&str; 3]> {
+    let mut parts = time.splitn(3, ':');
+    let hour = parts.next()?;
+    let minute = parts.next()?;
+    let second = parts.next().unwrap_or_default();
+    let digits = second
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(second.len());
+    Some([hour, minute, &second[..digits]])
+}
+
+/// Splits a datetime displayed as a date and a time separated by a `T` or a space, as chrono
+/// and jiff datetimes are, into the date and the values of the time inputs.
+pub fn datetime_parts(datetime: &str) -> Option<(&str, [&str; 3])> {
+    let (date, time) = datetime.split_once(['T', ' '])?;
+    Some((date, time_parts(time)?))
+}
+
+/// `HH:MM:SS` out of the values of the hour, minute and second inputs. `None` while the hour or
+/// the minute is empty or a part is out of range, an empty second counting as 0.
+fn time_text([hour, minute, second]: [&str; 3]) -> Option<String> {
+    let part = |value: &str, end: u8| value.trim().parse::<u8>().ok().filter(|part| *part < end);
+    let second = if second.trim().is_empty() {
+        0
+    } else {
+        part(second, 60)?
+    };
+    Some(format!(
+        "{:02}:{:02}:{:02}",
+        part(hour, 24)?,
+        part(minute, 60)?,
+        second
+    ))
+}
+
+/// Time out of the values of the hour, minute and second inputs of a time picker, `None` while
+/// they don't make one.
+pub fn parse_time<T: FromStr>(parts: [&str; 3]) -> Option<T> {
+    time_text(parts)?.parse().ok()
+}
+
+/// Time zone of a datetime displayed by chrono or jiff, to add to a datetime parsed in its
+/// place: the offset or `UTC` chrono shows after the time, or the `[zone]` annotation of jiff,
+/// whose offset is left for jiff to work out as it may differ at the new time.
+fn zone(datetime: &str) -> &str {
+    if let Some(annotation) = datetime.find('[') {
+        return &datetime[annotation..];
+    }
+    let Some((_, time)) = datetime.split_once(['T', ' ']) else {
+        return "";
+    };
+    let fraction = time
+        .get(8..)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .map_or(0, |rest| {
+            1 + rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len())
+        });
+    time.get(8 + fraction..).unwrap_or_default()
+}
+
+/// Datetime out of the date of a datetime picker, displayed as `YYYY-MM-DD`, and the values of
+/// its time inputs, `None` while they don't make one. A `zoned` datetime gets the time zone of
+/// `replacing`, the display of the value it replaces, or UTC without one.
+pub fn parse_datetime<T: FromStr>(
+    date: &str,
+    time: [&str; 3],
+    zoned: bool,
+    replacing: Option<&str>,
+) -> Option<T> {
+    let datetime = format!("{}T{}", date, time_text(time)?);
+    if !zoned {
+        return datetime.parse().ok();
+    }
+    match replacing {
+        Some(replacing) => format!("{}{}", datetime, zone(replacing)).parse().ok(),
+        None => UTC
+            .iter()
+            .find_map(|zone| format!("{}{}", datetime, zone).parse().ok()),
+    }
+}
+
+/// Value of a date range picker field, a `(start, end)` pair or a `start..end` range.
+pub trait DateRange {
+    /// Type of the start and end dates.
+    type Date;
+
+    /// The range from `start` to `end`.
+    fn from_bounds(start: Self::Date, end: Self::Date) -> Self;
+
+    /// The start and end dates of the range.
+    fn bounds(&self) -> (&Self::Date, &Self::Date);
+}
+
+impl<D> DateRange for (D, D) {
+    type Date = D;
+
+    fn from_bounds(start: D, end: D) -> Self {
+        (start, end)
+    }
+
+    fn bounds(&self) -> (&D, &D) {
+        (&self.0, &self.1)
+    }
+}
+
+impl<D> DateRange for Range<D> {
+    type Date = D;
+
+    fn from_bounds(start: D, end: D) -> Self {
+        start..end
+    }
+
+    fn bounds(&self) -> (&D, &D) {
+        (&self.start, &self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_parts() {
+        assert_eq!(time_parts("09:05:07"), Some(["09", "05", "07"]));
+        assert_eq!(time_parts("09:05:07.250"), Some(["09", "05", "07"]));
+        assert_eq!(time_parts("09:05"), Some(["09", "05", ""]));
+        assert_eq!(time_parts("09"), None);
+    }
+
+    #[test]
+    fn test_datetime_parts() {
+        assert_eq!(
+            datetime_parts("2024-03-01T09:05:07"),
+            Some(("2024-03-01", ["09", "05", "07"]))
+        );
+        assert_eq!(
+            datetime_parts("2024-03-01 09:05:07 UTC"),
+            Some(("2024-03-01", ["09", "05", "07"]))
+        );
+        assert_eq!(datetime_parts("2024-03-01"), None);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(
+            parse_time::<String>(["9", "5", ""]).as_deref(),
+            Some("09:05:00")
+        );
+        assert_eq!(
+            parse_time::<String>(["23", "59", "59"]).as_deref(),
+            Some("23:59:59")
+        );
+        assert_eq!(parse_time::<String>(["24", "00", "00"]), None);
+        assert_eq!(parse_time::<String>(["12", "", "00"]), None);
+    }
+
+    #[test]
+    fn test_zone() {
+        assert_eq!(zone("2024-03-01 09:05:07 UTC"), " UTC");
+        assert_eq!(zone("2024-03-01 09:05:07.250 +02:00"), " +02:00");
+        assert_eq!(
+            zone("2024-03-01T09:05:07+01:00[Europe/Paris]"),
+            "[Europe/Paris]"
+        );
+        assert_eq!(zone("2024-03-01T09:05:07"), "");
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let parse = |zoned, replacing| {
+            parse_datetime::<String>("2024-03-01", ["9", "5", ""], zoned, replacing)
+        };
+        assert_eq!(parse(false, None).as_deref(), Some("2024-03-01T09:05:00"));
+        assert_eq!(
+            parse(true, Some("2024-01-01 00:00:00 +02:00")).as_deref(),
+            Some("2024-03-01T09:05:00 +02:00")
+        );
+        assert_eq!(
+            parse(true, None).as_deref(),
+            Some("2024-03-01T09:05:00 UTC")
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use gpui::{Context, Task};
+
+/// Why a field fails a rule of its `#[gpui_form(validate(...))]`, as reported in the generated
+/// `XFormErrors`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The field is `required` but empty.
+    Required,
+    /// The text or collection is shorter than `min_len`.
+    TooShort { min_len: usize },
+    /// The text or collection is longer than `max_len`.
+    TooLong { max_len: usize },
+    /// The number is below `min`, as written in the attribute.
+    TooSmall { min: &'static str },
+    /// The number is above `max`, as written in the attribute.
+    TooLarge { max: &'static str },
+    /// The text doesn't match `pattern`.
+    PatternMismatch { pattern: &'static str },
+    /// Message of a failed `validate_async` validator.
+    Custom(String),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Required => write!(f, "is required"),
+            Self::TooShort { min_len } => write!(f, "must be at least {min_len} long"),
+            Self::TooLong { max_len } => write!(f, "must be at most {max_len} long"),
+            Self::TooSmall { min } => write!(f, "must be at least {min}"),
+            Self::TooLarge { max } => write!(f, "must be at most {max}"),
+            Self::PatternMismatch { pattern } => write!(f, "must match `{pattern}`"),
+            Self::Custom(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Progress and outcome of the `validate_async` validator of a field, kept by the generated
+/// `XFormState`.
+#[derive(Default)]
+pub struct AsyncValidation {
+    validating: bool,
+    error: Option<ValidationError>,
+    task: Option<Task<()>>,
+}
+
+impl AsyncValidation {
+    /// Wait between a change and the run of the validator, when the field doesn't set
+    /// `debounce_ms`.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+    /// Whether the validator is waiting out its debounce or running.
+    pub fn is_validating(&self) -> bool {
+        self.validating
+    }
+
+    /// Error of the last completed run.
+    pub fn error(&self) -> Option<&ValidationError> {
+        self.error.as_ref()
+    }
+
+    /// Runs `validator` on `value` once `debounce` has passed, cancelling the run in
+    /// progress. `validation` gets this state back out of the entity to store the outcome.
+    pub fn run<S, V, F>(
+        &mut self,
+        cx: &Context<S>,
+        debounce: Duration,
+        value: V,
+        validator: impl FnOnce(V) -> F + 'static,
+        validation: fn(&mut S) -> &mut AsyncValidation,
+    ) where
+        S: 'static,
+        V: 'static,
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        self.validating = true;
+        // dropping the previous task cancels it
+        self.task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(debounce).await;
+            let result = validator(value).await;
+            _ = this.update(cx, |this, cx| {
+                let validation = validation(this);
+                validation.validating = false;
+                validation.error = result.err().map(ValidationError::Custom);
+                cx.notify();
+            });
+        }));
+    }
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use gpui::{AppContext as _, TestAppContext};
+use gpui_component::input::InputEvent;
+use gpui_form::{AsyncValidation, GpuiForm, ValidationError};
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+struct Signup {
+    username: AsyncValidation,
+}
+
+/// Stands in for a lookup of the user names already taken.
+async fn username_available(username: String) -> Result<(), String> {
+    if username == "taken" {
+        Err("is already taken".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(entity)]
+struct Account {
+    #[gpui_form(component(input), validate_async = username_available, debounce_ms = 300)]
+    username: String,
+}
+
+fn validate(username: &str) -> impl FnOnce(&mut Signup, &mut gpui::Context<Signup>) {
+    let username = username.to_owned();
+    move |signup, cx| {
+        signup
+            .username
+            .run(cx, DEBOUNCE, username, username_available, |signup| {
+                &mut signup.username
+            });
+    }
+}
+
+#[gpui::test]
+fn reports_the_error_after_the_debounce(cx: &mut TestAppContext) {
+    let signup = cx.new(|_| Signup {
+        username: AsyncValidation::default(),
+    });
+
+    signup.update(cx, validate("taken"));
+    cx.run_until_parked();
+    signup.read_with(cx, |signup, _| {
+        assert!(signup.username.is_validating());
+        assert_eq!(signup.username.error(), None);
+    });
+
+    cx.executor().advance_clock(DEBOUNCE);
+    cx.run_until_parked();
+    signup.read_with(cx, |signup, _| {
+        assert!(!signup.username.is_validating());
+        assert_eq!(
+            signup.username.error(),
+            Some(&ValidationError::Custom("is already taken".to_owned()))
+        );
+    });
+}
+
+#[gpui::test]
+fn cancels_stale_runs(cx: &mut TestAppContext) {
+    let signup = cx.new(|_| Signup {
+        username: AsyncValidation::default(),
+    });
+
+    signup.update(cx, validate("taken"));
+    cx.executor().advance_clock(DEBOUNCE / 3);
+    signup.update(cx, validate("free"));
+
+    // the stale run would be done by now
+    cx.executor().advance_clock(DEBOUNCE * 2 / 3);
+    cx.run_until_parked();
+    signup.read_with(cx, |signup, _| {
+        assert!(signup.username.is_validating());
+        assert_eq!(signup.username.error(), None);
+    });
+
+    cx.executor().advance_clock(DEBOUNCE / 3);
+    cx.run_until_parked();
+    signup.read_with(cx, |signup, _| {
+        assert!(!signup.username.is_validating());
+        assert_eq!(signup.username.error(), None);
+    });
+}
+
+#[gpui::test]
+fn validates_the_edits_of_the_form_state(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form =
+        cx.update(|window, cx| cx.new(|cx| AccountFormState::new(window, cx, Account::default())));
+    cx.update(|window, cx| {
+        let username = form.read(cx).fields.username_input.clone();
+        username.update(cx, |state, cx| {
+            state.set_value("taken", window, cx);
+            cx.emit(InputEvent::Change);
+        });
+    });
+    cx.run_until_parked();
+    form.read_with(cx, |form, _| assert!(form.async_validation.is_validating()));
+
+    cx.executor().advance_clock(DEBOUNCE);
+    cx.run_until_parked();
+    form.read_with(cx, |form, _| {
+        assert!(!form.async_validation.is_validating());
+        assert!(form.async_validation.has_errors());
+    });
+}
@@ -0,0 +1,379 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use gpui::{AppContext as _, Entity, TestAppContext, VisualTestContext};
+use gpui_component::dropdown::DropdownEvent;
+use gpui_component::input::{InputEvent, InputState, NumberInputEvent, StepAction};
+use gpui_form::GpuiForm;
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(entity)]
+struct Booking {
+    #[gpui_form(component(input))]
+    name: String,
+    #[gpui_form(component(number_input))]
+    guests: u32,
+    #[gpui_form(component(date_range_picker))]
+    stay: (NaiveDate, NaiveDate),
+    #[gpui_form(component(time_picker))]
+    check_in: NaiveTime,
+    #[gpui_form(component(datetime_picker(seconds)))]
+    pickup: Option<NaiveDateTime>,
+    #[gpui_form(component(list(item = input)))]
+    stops: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(entity)]
+struct Labelled<T: Clone + From<String> + 'static> {
+    #[gpui_form(component(input))]
+    label: T,
+}
+
+#[derive(Clone, Debug, GpuiForm)]
+#[gpui_form(entity)]
+enum Delivery {
+    Pickup {
+        #[gpui_form(component(input))]
+        store: String,
+    },
+    Courier {
+        #[gpui_form(component(input))]
+        address: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, gpui_form::DropdownItem, strum::Display)]
+enum Room {
+    #[default]
+    Single,
+    Suite,
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(entity, infer)]
+struct Stay {
+    nights: u8,
+    room: Room,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+#[gpui_form(entity)]
+struct Line {
+    #[gpui_form(component(input))]
+    label: String,
+    #[gpui_form(component(number_input))]
+    quantity: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+#[gpui_form(entity)]
+struct Order {
+    #[gpui_form(component(nested))]
+    main: Line,
+    #[gpui_form(component(list(item = nested)))]
+    lines: Vec<Line>,
+    #[gpui_form(component(list(item = number_input)))]
+    discounts: Option<Vec<u8>>,
+}
+
+fn line(label: &str, quantity: u32) -> Line {
+    Line {
+        label: label.to_owned(),
+        quantity,
+    }
+}
+
+fn order() -> Order {
+    Order {
+        main: line("Desk", 1),
+        lines: vec![line("Chair", 4), line("Lamp", 2)],
+        discounts: None,
+    }
+}
+
+fn booking() -> Booking {
+    let date = NaiveDate::from_ymd_opt(2025, 6, 12).unwrap();
+    Booking {
+        name: "Ada".to_owned(),
+        guests: 2,
+        stay: (date, date),
+        check_in: NaiveTime::from_hms_opt(14, 30, 0).unwrap(),
+        pickup: None,
+        stops: Vec::new(),
+    }
+}
+
+fn planned_booking() -> Booking {
+    let date = |day| NaiveDate::from_ymd_opt(2025, 6, day).unwrap();
+    Booking {
+        name: "Ada".to_owned(),
+        guests: 2,
+        stay: (date(12), date(15)),
+        check_in: NaiveTime::from_hms_opt(14, 30, 0).unwrap(),
+        pickup: date(12).and_hms_opt(9, 15, 30),
+        stops: vec!["Lyon".to_owned(), "Nice".to_owned()],
+    }
+}
+
+fn edit(cx: &mut VisualTestContext, edits: Vec<(Entity<InputState>, &'static str)>) {
+    cx.update(|window, cx| {
+        for (state, value) in edits {
+            state.update(cx, |state, cx| {
+                state.set_value(value, window, cx);
+                cx.emit(InputEvent::Change);
+            });
+        }
+    });
+}
+
+#[gpui::test]
+fn reads_back_the_loaded_values(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    cx.update(|window, cx| {
+        let planned = planned_booking();
+        let mut fields = BookingFormComponents::build_fields(window, cx);
+        fields.load(&planned.clone().into(), window, cx);
+
+        let mut holder = BookingFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(holder.name, planned.name);
+        assert_eq!(holder.guests, planned.guests);
+        assert_eq!(holder.stay, planned.stay);
+        assert_eq!(holder.check_in, planned.check_in);
+        assert_eq!(holder.pickup, planned.pickup);
+        assert_eq!(holder.stops, planned.stops);
+
+        // loading fewer values drops the rows left over
+        fields.load(&booking().into(), window, cx);
+        assert!(fields.stops_list.is_empty());
+
+        let stay = Stay {
+            nights: 3,
+            room: Room::Suite,
+        };
+        let mut fields = StayFormComponents::build_fields(window, cx);
+        fields.load(&stay.into(), window, cx);
+        let mut holder = StayFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(holder.nights, 3);
+        assert_eq!(holder.room, Room::Suite);
+
+        let courier = Delivery::Courier {
+            address: "1 Rue Royale".to_owned(),
+        };
+        let mut fields = DeliveryFormComponents::build_fields(window, cx);
+        fields.load(&courier.into(), window, cx);
+        let mut holder = DeliveryFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(holder.variant, DeliveryFormVariant::Courier);
+        assert_eq!(holder.courier.address, "1 Rue Royale");
+    });
+}
+
+#[gpui::test]
+fn loads_and_reads_nested_forms_and_lists(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    cx.update(|window, cx| {
+        let mut fields = OrderFormComponents::build_fields(window, cx);
+        fields.load(&order().into(), window, cx);
+        assert_eq!(fields.lines_list.len(), 2);
+        assert!(fields.discounts_list.is_empty());
+
+        let mut holder = OrderFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(Order::from(holder), order());
+
+        // loading a shorter list drops the rows left over
+        let shorter = Order {
+            lines: vec![line("Shelf", 1)],
+            discounts: Some(vec![10, 5]),
+            ..order()
+        };
+        fields.load(&shorter.clone().into(), window, cx);
+        assert_eq!(fields.lines_list.len(), 1);
+        assert_eq!(fields.discounts_list.len(), 2);
+
+        let mut holder = OrderFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(Order::from(holder), shorter);
+    });
+}
+
+#[gpui::test]
+fn loads_the_original_into_the_fields(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form =
+        cx.update(|window, cx| cx.new(|cx| BookingFormState::new(window, cx, planned_booking())));
+    // only `new` needs the label to turn into the text of its input
+    let labelled = cx.update(|window, cx| {
+        let original = Labelled {
+            label: "Front desk".to_owned(),
+        };
+        cx.new(|cx| LabelledFormState::new(window, cx, original))
+    });
+    cx.update(|_window, cx| {
+        let value = |state: &Entity<InputState>| state.read(cx).value().to_string();
+        let fields = &form.read(cx).fields;
+        assert_eq!(value(&fields.name_input), "Ada");
+        assert_eq!(value(&fields.guests_number_input), "2");
+        assert_eq!(value(&fields.check_in_time_picker_hour), "14");
+        assert_eq!(value(&fields.check_in_time_picker_minute), "30");
+        assert_eq!(value(&fields.pickup_datetime_picker_second), "30");
+        assert_eq!(value(&fields.stops_list[1]), "Nice");
+        assert_eq!(value(&labelled.read(cx).fields.label_input), "Front desk");
+    });
+}
+
+#[gpui::test]
+fn syncs_edits_to_the_form_state(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| BookingFormState::new(window, cx, booking())));
+    let edits = cx.update(|_window, cx| {
+        let fields = &form.read(cx).fields;
+        vec![
+            (fields.name_input.clone(), "Grace"),
+            (fields.guests_number_input.clone(), "3"),
+            (fields.check_in_time_picker_hour.clone(), "16"),
+            (fields.check_in_time_picker_minute.clone(), "45"),
+            // not a time, so the value keeps its last one
+            (fields.pickup_datetime_picker_hour.clone(), "25"),
+        ]
+    });
+    edit(cx, edits);
+
+    cx.update(|_window, cx| {
+        let current = &form.read(cx).current;
+        assert_eq!(current.name, "Grace");
+        assert_eq!(current.guests, 3);
+        assert_eq!(
+            current.check_in,
+            NaiveTime::from_hms_opt(16, 45, 0).unwrap()
+        );
+        assert_eq!(current.pickup, None);
+    });
+}
+
+#[gpui::test]
+fn keeps_list_values_at_the_index_of_their_rows(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| BookingFormState::new(window, cx, booking())));
+    cx.update(|window, cx| {
+        form.update(cx, |form, cx| {
+            form.stops_list_push(window, cx);
+            form.stops_list_push(window, cx);
+        });
+    });
+    let second = cx.update(|_window, cx| form.read(cx).fields.stops_list[1].clone());
+    edit(cx, vec![(second.clone(), "Nice")]);
+
+    cx.update(|window, cx| {
+        form.update(cx, |form, cx| {
+            assert_eq!(form.current.stops, ["", "Nice"]);
+            form.stops_list_move(1, 0, window, cx);
+            // past the last row, so left alone
+            form.stops_list_remove(5, window, cx);
+            form.stops_list_move(0, 5, window, cx);
+            form.stops_list_remove(1, window, cx);
+            assert_eq!(form.current.stops, ["Nice"]);
+        });
+    });
+
+    // the moved row now writes to its new index
+    edit(cx, vec![(second, "Paris")]);
+    cx.update(|_window, cx| assert_eq!(form.read(cx).current.stops, ["Paris"]));
+}
+
+#[gpui::test]
+fn writes_to_the_new_index_of_rows_moved_before_resubscribing(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| BookingFormState::new(window, cx, booking())));
+    cx.update(|window, cx| {
+        form.update(cx, |form, cx| {
+            form.stops_list_push(window, cx);
+            form.stops_list_push(window, cx);
+        });
+    });
+    let second = cx.update(|_window, cx| form.read(cx).fields.stops_list[1].clone());
+
+    cx.update(|window, cx| {
+        form.update(cx, |form, cx| {
+            // moved through `BookingFormComponents`, so the rows keep their subscriptions
+            BookingFormComponents::stops_list_move(&mut form.fields.stops_list, 1, 0);
+            form.resubscribe(window, cx);
+        });
+    });
+
+    edit(cx, vec![(second, "Paris")]);
+    cx.update(|_window, cx| assert_eq!(form.read(cx).current.stops, ["Paris", ""]));
+}
+
+#[gpui::test]
+fn keeps_what_was_typed_in_other_variants(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let pickup = Delivery::Pickup {
+        store: "Lyon".to_owned(),
+    };
+    let form = cx.update(|window, cx| cx.new(|cx| DeliveryFormState::new(window, cx, pickup)));
+    let (selector, address) = cx.update(|_window, cx| {
+        let fields = &form.read(cx).fields;
+        (
+            fields.variant_selector.clone(),
+            fields.courier_variant.address_input.clone(),
+        )
+    });
+    edit(cx, vec![(address, "1 Rue Royale")]);
+    cx.update(|_window, cx| {
+        selector.update(cx, |_state, cx| {
+            cx.emit(DropdownEvent::Confirm(Some(DeliveryFormVariant::Courier)));
+        });
+    });
+
+    cx.update(|_window, cx| {
+        let current = &form.read(cx).current;
+        assert_eq!(current.variant, DeliveryFormVariant::Courier);
+        assert_eq!(current.courier.address, "1 Rue Royale");
+        assert_eq!(current.pickup.store, "Lyon");
+    });
+}
+
+#[gpui::test]
+fn syncs_inferred_fields_to_the_form_state(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| StayFormState::new(window, cx, Stay::default())));
+    let (nights, room) = cx.update(|_window, cx| {
+        let fields = &form.read(cx).fields;
+        (fields.nights_inferred.clone(), fields.room_inferred.clone())
+    });
+    edit(cx, vec![(nights.clone(), "254")]);
+    cx.update(|_window, cx| {
+        nights.update(cx, |_state, cx| {
+            cx.emit(NumberInputEvent::Step(StepAction::Increment));
+            // saturating at `u8::MAX`
+            cx.emit(NumberInputEvent::Step(StepAction::Increment));
+        });
+        room.update(cx, |_state, cx| {
+            cx.emit(DropdownEvent::Confirm(Some(Room::Suite)));
+        });
+    });
+
+    cx.update(|_window, cx| {
+        let current = &form.read(cx).current;
+        assert_eq!(current.nights, u8::MAX);
+        assert_eq!(current.room, Room::Suite);
+    });
+}
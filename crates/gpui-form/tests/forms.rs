@@ -0,0 +1,261 @@
+use gpui_component::dropdown::DropdownItem as _;
+use gpui_form::{DropdownItem, GpuiForm};
+
+#[derive(Clone, Debug, PartialEq, GpuiForm)]
+enum Payment {
+    Card {
+        #[gpui_form(component(input))]
+        number: String,
+    },
+    #[gpui_form(label = "Bank transfer")]
+    Transfer {
+        #[gpui_form(component(input))]
+        iban: String,
+        #[gpui_form(component(number_input))]
+        days: u8,
+    },
+    CashOnDelivery,
+}
+
+#[test]
+fn converts_enums_through_their_value_holder() {
+    let transfer = Payment::Transfer {
+        iban: "FR76".to_owned(),
+        days: 2,
+    };
+    let holder = PaymentFormValueHolder::from(transfer.clone());
+    assert_eq!(holder.variant, PaymentFormVariant::Transfer);
+    assert_eq!(holder.transfer.iban, "FR76");
+    assert_eq!(Payment::from(holder), transfer);
+
+    let holder = PaymentFormValueHolder::from(Payment::CashOnDelivery);
+    assert_eq!(holder.variant, PaymentFormVariant::CashOnDelivery);
+    assert_eq!(Payment::from(holder), Payment::CashOnDelivery);
+}
+
+#[test]
+fn keeps_the_values_of_other_variants_in_the_value_holder() {
+    let mut holder = PaymentFormValueHolder::from(Payment::Card {
+        number: "4242".to_owned(),
+    });
+    holder.variant = PaymentFormVariant::Transfer;
+    holder.transfer.iban = "FR76".to_owned();
+    assert_eq!(holder.card.number, "4242");
+    assert_eq!(
+        Payment::from(holder),
+        Payment::Transfer {
+            iban: "FR76".to_owned(),
+            days: 0,
+        }
+    );
+}
+
+#[test]
+fn titles_variants_by_label_or_name() {
+    assert_eq!(PaymentFormVariant::Card.title().to_string(), "Card");
+    assert_eq!(
+        PaymentFormVariant::Transfer.title().to_string(),
+        "Bank transfer"
+    );
+    assert_eq!(
+        PaymentFormVariant::CashOnDelivery.title().to_string(),
+        "Cash On Delivery"
+    );
+}
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+#[gpui_form(register(Threshold<u32>, Threshold<f64>))]
+struct Threshold<T: gpui_form::NumRegex> {
+    #[gpui_form(component(input))]
+    name: String,
+    #[gpui_form(component(number_input))]
+    value: T,
+}
+
+#[test]
+fn carries_generics_to_the_value_holder() {
+    let threshold = Threshold {
+        name: "limit".to_owned(),
+        value: 2.5_f64,
+    };
+    let holder = ThresholdFormValueHolder::<f64>::from(threshold.clone());
+    assert_eq!(holder.value, 2.5);
+    assert_eq!(Threshold::from(holder), threshold);
+}
+
+#[cfg(feature = "inventory")]
+#[test]
+fn registers_a_shape_per_instantiation() {
+    use gpui_form::core::registry::{GpuiFormShape, inventory};
+
+    let mut generic_args: Vec<_> = inventory::iter::<GpuiFormShape>
+        .into_iter()
+        .filter(|shape| shape.struct_name == "Threshold")
+        .map(|shape| shape.generic_args)
+        .collect();
+    generic_args.sort();
+    assert_eq!(generic_args, [Some("f64"), Some("u32")]);
+}
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+#[gpui_form(transparent)]
+struct Email(String);
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+struct Contact(
+    #[gpui_form(component(input))] String,
+    #[gpui_form(component(input))] Email,
+);
+
+#[test]
+fn converts_transparent_newtypes_to_and_from_their_inner_type() {
+    let email = Email::from("ada@example.com".to_owned());
+    assert_eq!(email, Email("ada@example.com".to_owned()));
+    assert_eq!(String::from(email), "ada@example.com");
+}
+
+#[test]
+fn names_tuple_struct_fields_by_position() {
+    let contact = Contact("Ada".to_owned(), Email("ada@example.com".to_owned()));
+    let holder = ContactFormValueHolder::from(contact.clone());
+    assert_eq!(holder.field_0, "Ada");
+    assert_eq!(holder.field_1, Email("ada@example.com".to_owned()));
+    assert_eq!(Contact::from(holder), contact);
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+struct Login(
+    #[gpui_form(component(input))] String,
+    #[gpui_form(component(input(masked)))] String,
+);
+
+#[test]
+fn masks_tuple_struct_fields_in_debug() {
+    let holder = LoginFormValueHolder::from(Login("ada".to_owned(), "hunter2".to_owned()));
+    let debug = format!("{holder:?}");
+    assert!(debug.contains("ada"));
+    assert!(!debug.contains("hunter2"));
+}
+
+#[derive(Clone, Debug, Default, DropdownItem, PartialEq)]
+enum Plan {
+    #[default]
+    Free,
+    Pro,
+}
+
+impl std::fmt::Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(infer)]
+struct Subscription {
+    email: String,
+    seats: u32,
+    discount: Option<f64>,
+    plan: Plan,
+    renew: bool,
+}
+
+#[test]
+fn gives_inferred_fields_the_state_of_their_component() {
+    use gpui::Entity;
+    use gpui_component::{dropdown::DropdownState, input::InputState};
+
+    fn seats(fields: &SubscriptionFormFields) -> &Entity<InputState> {
+        &fields.seats_inferred
+    }
+    fn discount(fields: &SubscriptionFormFields) -> &Entity<InputState> {
+        &fields.discount_inferred
+    }
+    fn plan(fields: &SubscriptionFormFields) -> &Entity<DropdownState<Vec<Plan>>> {
+        &fields.plan_inferred
+    }
+    let _ = (seats, discount, plan);
+}
+
+#[cfg(feature = "inventory")]
+#[test]
+fn records_the_inferred_components_in_the_shape() {
+    use gpui_form::core::registry::GpuiFormShape;
+
+    let shape = GpuiFormShape::find("Subscription").unwrap();
+    let components: Vec<_> = shape
+        .components
+        .iter()
+        .map(|field| {
+            (
+                field.field_name,
+                field.behaviour.to_string(),
+                field.inferred,
+            )
+        })
+        .collect();
+    assert_eq!(
+        components,
+        [
+            ("email", "input".to_owned(), false),
+            ("seats", "number_input".to_owned(), true),
+            ("discount", "number_input".to_owned(), true),
+            ("plan", "dropdown".to_owned(), true),
+            ("renew", "checkbox".to_owned(), false),
+        ]
+    );
+    assert_eq!(shape.components[3].component_suffix(), "inferred");
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+struct Signup {
+    #[gpui_form(
+        component(input),
+        label = "E-mail",
+        description = "Where we write to you",
+        placeholder = "ada@example.com"
+    )]
+    email: String,
+    #[gpui_form(component(checkbox))]
+    terms: bool,
+}
+
+#[cfg(feature = "inventory")]
+#[test]
+fn records_literal_labels_in_the_shape() {
+    use gpui_form::core::registry::GpuiFormShape;
+
+    let shape = GpuiFormShape::find("Signup").unwrap();
+    let email = &shape.components[0];
+    assert_eq!(email.label, Some("E-mail"));
+    assert_eq!(email.description, Some("Where we write to you"));
+    assert_eq!(email.placeholder, Some("ada@example.com"));
+    let terms = &shape.components[1];
+    assert_eq!((terms.label, terms.description), (None, None));
+}
+
+/// Round trips `value` through the value holder of any form.
+fn through_value_holder<F: gpui_form::Form>(value: F) -> F {
+    let holder: F::ValueHolder = value.into();
+    holder.into()
+}
+
+#[test]
+fn implements_form_for_structs_enums_and_registered_generics() {
+    use gpui_form::Form;
+
+    assert_eq!(Signup::shape().struct_name, "Signup");
+    assert_eq!(Payment::shape().struct_name, "Payment");
+    assert_eq!(<Threshold<u32>>::shape().generic_args, Some("u32"));
+
+    let signup = through_value_holder(Signup {
+        email: "ada@example.com".to_owned(),
+        terms: true,
+    });
+    assert_eq!(signup.email, "ada@example.com");
+    assert!(signup.terms);
+    assert_eq!(
+        through_value_holder(Payment::CashOnDelivery),
+        Payment::CashOnDelivery
+    );
+}
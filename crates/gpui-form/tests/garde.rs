@@ -0,0 +1,37 @@
+#![cfg(feature = "validate")]
+
+use garde::Validate;
+use gpui_form::GpuiForm;
+
+#[derive(Clone, Debug, Default, GpuiForm, Validate)]
+struct Account {
+    #[gpui_form(component(input))]
+    #[garde(length(min = 3))]
+    name: String,
+    #[gpui_form(component(input))]
+    #[garde(email)]
+    email: String,
+    #[gpui_form(component(input))]
+    #[garde(skip)]
+    nickname: String,
+}
+
+#[test]
+fn reports_the_garde_errors_of_each_field() {
+    let valid = AccountFormValueHolder::from(Account {
+        name: "Ada".to_owned(),
+        email: "ada@example.com".to_owned(),
+        nickname: String::new(),
+    });
+    assert!(valid.validate_fields().is_empty());
+
+    let holder = AccountFormValueHolder {
+        name: "Ad".to_owned(),
+        email: "ada".to_owned(),
+        ..valid
+    };
+    let errors = holder.validate_fields();
+    assert_eq!(errors["name"].len(), 1);
+    assert_eq!(errors["email"].len(), 1);
+    assert!(!errors.contains_key("nickname"));
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use gpui::{AppContext as _, TestAppContext};
+use gpui_form::GpuiForm;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    gpui_form::DropdownItem,
+    strum::Display,
+    strum::EnumIter,
+)]
+enum Role {
+    #[default]
+    Admin,
+    Editor,
+    Viewer,
+}
+
+#[derive(Clone, Debug, Default, GpuiForm)]
+#[gpui_form(entity)]
+struct Team {
+    #[gpui_form(component(multi_select))]
+    roles: Vec<Role>,
+    #[gpui_form(component(multi_select))]
+    reviewers: HashSet<Role>,
+    #[gpui_form(component(multi_select))]
+    guests: Option<Vec<Role>>,
+}
+
+fn team() -> Team {
+    Team {
+        roles: vec![Role::Editor, Role::Admin],
+        reviewers: HashSet::from([Role::Viewer]),
+        guests: None,
+    }
+}
+
+#[gpui::test]
+fn reads_back_the_loaded_values(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    cx.update(|window, cx| {
+        let mut fields = TeamFormComponents::build_fields(window, cx);
+        fields.load(&team().into(), window, cx);
+
+        let mut holder = TeamFormValueHolder::default();
+        fields.read(&mut holder, cx);
+        assert_eq!(holder.roles, vec![Role::Editor, Role::Admin]);
+        assert_eq!(holder.reviewers, HashSet::from([Role::Viewer]));
+        assert_eq!(holder.guests, None);
+        assert_eq!(fields.roles_multi_select.read(cx).selected_value(), None);
+    });
+}
+
+#[gpui::test]
+fn syncs_selected_values_to_the_form_state(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| TeamFormState::new(window, cx, team())));
+    cx.update(|_window, cx| {
+        form.update(cx, |form, cx| {
+            form.fields
+                .roles_multi_select_selected
+                .update(cx, |roles, cx| {
+                    roles.retain(|role| *role != Role::Editor);
+                    cx.notify();
+                });
+            form.fields
+                .reviewers_multi_select_selected
+                .update(cx, |reviewers, cx| {
+                    reviewers.push(Role::Admin);
+                    cx.notify();
+                });
+            form.fields
+                .guests_multi_select_selected
+                .update(cx, |guests, cx| {
+                    guests.push(Role::Viewer);
+                    cx.notify();
+                });
+        });
+    });
+
+    cx.update(|_window, cx| {
+        let current = &form.read(cx).current;
+        assert_eq!(current.roles, vec![Role::Admin]);
+        assert_eq!(
+            current.reviewers,
+            HashSet::from([Role::Viewer, Role::Admin])
+        );
+        assert_eq!(current.guests, Some(vec![Role::Viewer]));
+    });
+}
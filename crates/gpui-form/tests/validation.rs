@@ -0,0 +1,128 @@
+use gpui::{AppContext as _, TestAppContext};
+use gpui_component::input::InputEvent;
+use gpui_form::{GpuiForm, ValidationError};
+
+#[derive(Clone, Debug, Default, PartialEq, GpuiForm)]
+#[gpui_form(entity, track_changes, validate_with = Self::check)]
+struct Signup {
+    #[gpui_form(
+        component(input),
+        validate(required, min_len = 3, pattern = "^[a-z]+$")
+    )]
+    username: String,
+    #[gpui_form(component(number_input), validate(min = 18, max = 150))]
+    age: u8,
+    #[gpui_form(component(input), validate(required))]
+    password: String,
+    #[gpui_form(component(input))]
+    confirm_password: String,
+}
+
+impl Signup {
+    fn check(holder: &SignupFormValueHolder, errors: &mut SignupFormErrors) {
+        if holder.password != holder.confirm_password {
+            errors.confirm_password = Some(ValidationError::Custom("must match".to_owned()));
+        }
+    }
+}
+
+fn signup() -> Signup {
+    Signup {
+        username: "ada".to_owned(),
+        age: 36,
+        password: "secret".to_owned(),
+        confirm_password: "secret".to_owned(),
+    }
+}
+
+#[test]
+fn reports_the_first_rule_each_field_fails() {
+    let holder = SignupFormValueHolder::from(Signup {
+        username: "Ad".to_owned(),
+        age: 12,
+        password: String::new(),
+        ..signup()
+    });
+
+    let errors = holder.validate();
+    assert!(!errors.is_empty());
+    assert_eq!(
+        errors.username,
+        Some(ValidationError::TooShort { min_len: 3 })
+    );
+    assert_eq!(errors.age, Some(ValidationError::TooSmall { min: "18" }));
+    assert_eq!(errors.password, Some(ValidationError::Required));
+    // `validate_with` waits for every field to pass its own rules
+    assert_eq!(errors.confirm_password, None);
+
+    let holder = SignupFormValueHolder::from(Signup {
+        username: "Ada".to_owned(),
+        age: 200,
+        ..signup()
+    });
+    let errors = holder.validate();
+    assert_eq!(
+        errors.username,
+        Some(ValidationError::PatternMismatch {
+            pattern: "^[a-z]+$"
+        })
+    );
+    assert_eq!(errors.age, Some(ValidationError::TooLarge { max: "150" }));
+}
+
+#[test]
+fn runs_validate_with_once_the_fields_pass() {
+    assert!(SignupFormValueHolder::from(signup()).validate().is_empty());
+
+    let holder = SignupFormValueHolder::from(Signup {
+        confirm_password: "secert".to_owned(),
+        ..signup()
+    });
+    assert_eq!(
+        holder.validate(),
+        SignupFormErrors {
+            confirm_password: Some(ValidationError::Custom("must match".to_owned())),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn names_the_changed_fields_of_the_holder() {
+    let original = signup();
+    let mut holder = SignupFormValueHolder::from(original.clone());
+    assert!(holder.changed_fields(&original).is_empty());
+    assert!(!holder.is_dirty(&original));
+
+    holder.age = 37;
+    holder.confirm_password = "secert".to_owned();
+    assert_eq!(
+        holder.changed_fields(&original),
+        ["age", "confirm_password"]
+    );
+    assert!(holder.is_dirty(&original));
+}
+
+#[gpui::test]
+fn tracks_the_changes_of_the_form_state(cx: &mut TestAppContext) {
+    cx.update(gpui_component::init);
+    let cx = cx.add_empty_window();
+
+    let form = cx.update(|window, cx| cx.new(|cx| SignupFormState::new(window, cx, signup())));
+    cx.update(|window, cx| {
+        assert!(!form.read(cx).is_dirty());
+
+        let username = form.read(cx).fields.username_input.clone();
+        username.update(cx, |state, cx| {
+            state.set_value("grace", window, cx);
+            cx.emit(InputEvent::Change);
+        });
+    });
+
+    cx.update(|_window, cx| {
+        let form = form.read(cx);
+        assert_eq!(form.changed_fields(), ["username"]);
+        assert!(form.is_dirty());
+        assert!(form.current.validate().is_empty());
+    });
+}
@@ -3,6 +3,16 @@ use std::sync::OnceLock;
 
 pub trait NumRegex {
     fn validation_regex() -> &'static Regex;
+
+    /// The value one above `self`, saturating at the largest one.
+    fn step_up(&self) -> Self
+    where
+        Self: Sized;
+
+    /// The value one below `self`, saturating at the smallest one.
+    fn step_down(&self) -> Self
+    where
+        Self: Sized;
 }
 
 macro_rules! static_regex {
@@ -19,6 +29,14 @@ macro_rules! impl_numregex_signed {
                 fn validation_regex() -> &'static Regex {
                     static_regex!(r"^[+-]?(?:0|[1-9]\d*)$")
                 }
+
+                fn step_up(&self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn step_down(&self) -> Self {
+                    self.saturating_sub(1)
+                }
             }
         )*
     };
@@ -31,6 +49,14 @@ macro_rules! impl_numregex_unsigned {
                 fn validation_regex() -> &'static Regex {
                     static_regex!(r"^(?:0|[1-9]\d*)$")
                 }
+
+                fn step_up(&self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn step_down(&self) -> Self {
+                    self.saturating_sub(1)
+                }
             }
         )*
     };
@@ -44,6 +70,14 @@ macro_rules! impl_numregex_float {
                     // Matches: integers, decimals, scientific notation, infinity, NaN
                     static_regex!(r"^[+-]?(?:(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?|inf|infinity|nan)$")
                 }
+
+                fn step_up(&self) -> Self {
+                    self + 1.
+                }
+
+                fn step_down(&self) -> Self {
+                    self - 1.
+                }
             }
         )*
     };
@@ -60,6 +94,14 @@ impl NumRegex for rust_decimal::Decimal {
         // Supports scientific notation as rust_decimal can parse it
         static_regex!(r"^[+-]?(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?$")
     }
+
+    fn step_up(&self) -> Self {
+        self.saturating_add(rust_decimal::Decimal::ONE)
+    }
+
+    fn step_down(&self) -> Self {
+        self.saturating_sub(rust_decimal::Decimal::ONE)
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +154,15 @@ mod tests {
         assert!(!Decimal::validation_regex().is_match("nan"));
     }
 
+    #[test]
+    fn test_steps() {
+        assert_eq!(5u8.step_up(), 6);
+        assert_eq!(u8::MAX.step_up(), u8::MAX);
+        assert_eq!(0u32.step_down(), 0);
+        assert_eq!(i64::MIN.step_down(), i64::MIN);
+        assert_eq!(0.5f64.step_down(), -0.5);
+    }
+
     #[test]
     fn test_regex_compilation() {
         let _ = i32::validation_regex();
@@ -1,16 +1,25 @@
 use some_lib::structs::user::*;
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, ParentElement as _, Render, Styled, Subscription, Window,
+    IntoElement, ParentElement as _, Render, SharedString, Styled, Subscription, Window,
+    prelude::FluentBuilder as _, px,
 };
 use gpui_component::{
-    checkbox::Checkbox, date_picker::{DatePicker, DatePickerEvent, DatePickerState},
-    divider::Divider, dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
+    IconName, Sizable as _, button::{Button, ButtonVariants as _},
+    calendar::Date, checkbox::Checkbox,
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    date_picker::{DatePicker, DatePickerEvent, DatePickerState},
+    divider::Divider,
+    dropdown::{Dropdown, DropdownEvent, DropdownItem as _, DropdownState, SearchableVec},
     form::{form_field, v_form},
+    group_box::GroupBox,
     input::{
-        InputEvent, InputState, NumberInput, NumberInputEvent, StepAction, TextInput,
+        InputEvent, InputState, NumberInput, NumberInputEvent, OtpInput, OtpState,
+        StepAction, TextInput,
     },
-    switch::Switch, v_flex,
+    radio::{Radio, RadioGroup},
+    slider::{Slider, SliderEvent, SliderState},
+    h_flex, switch::Switch, tag::Tag, v_flex,
 };
 use rust_decimal::Decimal;
 use std::sync::Arc;
@@ -239,7 +248,17 @@ impl UserForm {
             }
         }
     }
+    fn submit(&mut self, cx: &mut Context<Self>) {
+        let errors = self.current_data.validate_fields();
+        let changed = self.current_data.changed_fields(&self.original_data);
+        if !errors.is_empty() || changed.is_empty() {
+            return;
+        }
+        self.original_data = Arc::new(self.current_data.clone().into());
+        cx.notify();
+    }
     fn new(window: &mut Window, cx: &mut Context<Self>, original_data: User) -> Self {
+        let current_data: UserFormValueHolder = original_data.clone().into();
         let username_input = cx.new(|cx| UserFormComponents::username_input(window, cx));
         let email_input = cx.new(|cx| UserFormComponents::email_input(window, cx));
         let age_number_input = cx
@@ -265,18 +284,20 @@ impl UserForm {
             cx.subscribe_in(& birth_date_date_picker, window,
             Self::on_birth_date_date_picker_event)
         ];
+        let mut fields = UserFormFields {
+            username_input,
+            email_input,
+            age_number_input,
+            balance_number_input,
+            preferred_dropdown,
+            country_dropdown,
+            birth_date_date_picker,
+        };
+        fields.load(&current_data, window, cx);
         Self {
-            original_data: Arc::new(original_data.clone()),
-            current_data: original_data.into(),
-            fields: UserFormFields {
-                username_input,
-                email_input,
-                age_number_input,
-                balance_number_input,
-                preferred_dropdown,
-                country_dropdown,
-                birth_date_date_picker,
-            },
+            original_data: Arc::new(original_data),
+            current_data,
+            fields,
             focus_handle: cx.focus_handle(),
             _subscriptions,
         }
@@ -284,6 +305,8 @@ impl UserForm {
 }
 impl Render for UserForm {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let errors = self.current_data.validate_fields();
+        let changed = self.current_data.changed_fields(&self.original_data);
         v_flex()
             .key_context(CONTEXT)
             .id("user-form")
@@ -298,25 +321,113 @@ impl Render for UserForm {
                         form_field()
                             .label(UserLabelFtl::Username.to_string())
                             .description(UserDescriptionFtl::Username.to_string())
-                            .child(TextInput::new(&self.fields.username_input)),
+                            .child(TextInput::new(&self.fields.username_input))
+                            .children(
+                                errors
+                                    .get("username")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"username")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::Email.to_string())
                             .description(UserDescriptionFtl::Email.to_string())
-                            .child(TextInput::new(&self.fields.email_input)),
+                            .child(TextInput::new(&self.fields.email_input))
+                            .children(
+                                errors
+                                    .get("email")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"email")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::Age.to_string())
                             .description(UserDescriptionFtl::Age.to_string())
-                            .child(NumberInput::new(&self.fields.age_number_input)),
+                            .child(NumberInput::new(&self.fields.age_number_input))
+                            .children(
+                                errors
+                                    .get("age")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"age")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::Balance.to_string())
                             .description(UserDescriptionFtl::Balance.to_string())
-                            .child(NumberInput::new(&self.fields.balance_number_input)),
+                            .child(NumberInput::new(&self.fields.balance_number_input))
+                            .children(
+                                errors
+                                    .get("balance")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"balance")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
@@ -329,12 +440,34 @@ impl Render for UserForm {
                                     .checked(self.current_data.subscribe_newsletter)
                                     .on_click(
                                         cx
-                                            .listener(|v, _, _, _| {
+                                            .listener(move |v, _, _, _| {
                                                 v.current_data.subscribe_newsletter = !v
                                                     .current_data
                                                     .subscribe_newsletter;
                                             }),
                                     ),
+                            )
+                            .children(
+                                errors
+                                    .get("subscribe_newsletter")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"subscribe_newsletter")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
                             ),
                     )
                     .child(
@@ -353,27 +486,122 @@ impl Render for UserForm {
                                                 cx.notify();
                                             }),
                                     ),
+                            )
+                            .children(
+                                errors
+                                    .get("enable_notifications")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"enable_notifications")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
                             ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::Preferred.to_string())
                             .description(UserDescriptionFtl::Preferred.to_string())
-                            .child(Dropdown::new(&self.fields.preferred_dropdown)),
+                            .child(Dropdown::new(&self.fields.preferred_dropdown))
+                            .children(
+                                errors
+                                    .get("preferred")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"preferred")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::Country.to_string())
                             .description(UserDescriptionFtl::Country.to_string())
-                            .child(Dropdown::new(&self.fields.country_dropdown)),
+                            .child(Dropdown::new(&self.fields.country_dropdown))
+                            .children(
+                                errors
+                                    .get("country")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"country")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     )
                     .child(
                         form_field()
                             .label(UserLabelFtl::BirthDate.to_string())
                             .description(UserDescriptionFtl::BirthDate.to_string())
-                            .child(DatePicker::new(&self.fields.birth_date_date_picker)),
+                            .child(DatePicker::new(&self.fields.birth_date_date_picker))
+                            .children(
+                                errors
+                                    .get("birth_date")
+                                    .into_iter()
+                                    .flatten()
+                                    .map(|error| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::red())
+                                            .child(error.clone())
+                                    }),
+                            )
+                            .children(
+                                changed
+                                    .contains(&"birth_date")
+                                    .then(|| {
+                                        ::gpui::div()
+                                            .text_xs()
+                                            .text_color(::gpui::blue())
+                                            .child("Modified")
+                                    }),
+                            ),
                     ),
             )
+            .child(
+                Button::new("submit")
+                    .primary()
+                    .label("Save")
+                    .disabled(!errors.is_empty() || changed.is_empty())
+                    .on_click(cx.listener(|this, _, _, cx| this.submit(cx))),
+            )
             .child(Divider::horizontal())
             .absolute()
             .child(format!("{:?}", self.current_data))
@@ -18,9 +18,16 @@ fn main() {
     println!("Generating forms in: {}", output_dir.display());
 
     for struct_info in inventory::iter::<GpuiFormShape>() {
+        if struct_info.variant_of.is_some() {
+            continue;
+        }
         println!("Thing : {:?}", struct_info);
         let syn_file = layout(struct_info);
-        let struct_snek_case_name = struct_info.struct_name.to_snake_case();
+        let struct_snek_case_name = match struct_info.generic_args {
+            Some(generic_args) => format!("{}_{}", struct_info.struct_name, generic_args),
+            None => struct_info.struct_name.to_owned(),
+        }
+        .to_snake_case();
         let file_path = output_dir.join(format!("{}.rs", struct_snek_case_name));
 
         let formatted_code = prettyplease::unparse(&syn_file);
@@ -42,6 +49,12 @@ fn layout(data: &GpuiFormShape) -> syn::File {
     let struct_name_uw_ident = format_ident!("{}FormValueHolder", struct_name_ident);
     let struct_name_form_ident = adapter.identities.struct_form_ident();
     let struct_name_form_fields_ident = adapter.identities.struct_form_fields_ident();
+    let generic_args = adapter.identities.generic_args_tokens();
+    let struct_name_path = if generic_args.is_empty() {
+        quote! { #struct_name_ident }
+    } else {
+        quote! { #struct_name_ident::#generic_args }
+    };
     let form_id_literal = adapter.identities.form_id_literal();
 
     let struct_name_path_qualifier =
@@ -73,20 +86,67 @@ fn layout(data: &GpuiFormShape) -> syn::File {
 
     let event_handlers_tokens = adapter.event_handlers().unwrap_or_default();
 
+    // validated forms show their errors and can't be submitted while invalid
+    let garde_errors = data
+        .validated
+        .then(|| quote! { let errors = self.current_data.validate_fields(); });
+    let rule_errors = data
+        .checked
+        .then(|| quote! { let form_errors = self.current_data.validate(); });
+    // forms tracking changes mark the changed fields and can only be saved once modified
+    let changes = data.tracked.then(|| {
+        quote! { let changed = self.current_data.changed_fields(&self.original_data); }
+    });
+    let validation_tokens = quote! { #garde_errors #rule_errors #changes };
+    let mut invalid = Vec::new();
+    if data.validated {
+        invalid.push(quote! { !errors.is_empty() });
+    }
+    if data.checked {
+        invalid.push(quote! { !form_errors.is_empty() });
+    }
+    if data.tracked {
+        invalid.push(quote! { changed.is_empty() });
+    }
+    let (submit_disabled, submit_check) = if invalid.is_empty() {
+        (quote! { false }, quote! {})
+    } else {
+        (
+            quote! { #(#invalid)||* },
+            quote! {
+                #validation_tokens
+                if #(#invalid)||* {
+                    return;
+                }
+            },
+        )
+    };
+
     let import_tokens = quote! {
       #target_types_import
       use gpui::{
           App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement,
-          IntoElement, ParentElement as _, Render, Styled, Subscription, Window,
+          IntoElement, ParentElement as _, Render, SharedString, Styled, Subscription, Window,
+          prelude::FluentBuilder as _, px,
       };
       use gpui_component::{
-          checkbox::Checkbox, date_picker::{DatePicker, DatePickerEvent, DatePickerState},
-          divider::Divider, dropdown::{Dropdown, DropdownEvent, DropdownState, SearchableVec},
+          IconName, Sizable as _,
+          button::{Button, ButtonVariants as _},
+          calendar::Date,
+          checkbox::Checkbox,
+          color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+          date_picker::{DatePicker, DatePickerEvent, DatePickerState},
+          divider::Divider,
+          dropdown::{Dropdown, DropdownEvent, DropdownItem as _, DropdownState, SearchableVec},
           form::{form_field, v_form},
+          group_box::GroupBox,
           input::{
-              InputEvent, InputState, NumberInput, NumberInputEvent, StepAction, TextInput,
+              InputEvent, InputState, NumberInput, NumberInputEvent, OtpInput, OtpState, StepAction,
+              TextInput,
           },
-          switch::Switch, v_flex,
+          radio::{Radio, RadioGroup},
+          slider::{Slider, SliderEvent, SliderState},
+          h_flex, switch::Switch, tag::Tag, v_flex,
       };
       use rust_decimal::Decimal;
       use std::sync::Arc;
@@ -103,9 +163,9 @@ fn layout(data: &GpuiFormShape) -> syn::File {
 
       #[gpui_storybook::story]
       pub struct #struct_name_form_ident {
-          original_data: Arc<#struct_name_ident>,
-          current_data: #struct_name_uw_ident,
-          fields: #struct_name_form_fields_ident,
+          original_data: Arc<#struct_name_ident #generic_args>,
+          current_data: #struct_name_uw_ident #generic_args,
+          fields: #struct_name_form_fields_ident #generic_args,
           focus_handle: FocusHandle,
           #subscriptions_field
       }
@@ -118,32 +178,43 @@ fn layout(data: &GpuiFormShape) -> syn::File {
 
       impl gpui_storybook::Story for #struct_name_form_ident {
           fn title() -> String {
-              #struct_name_ident::this_ftl()
+              #struct_name_path::this_ftl()
           }
 
           fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render + Focusable> {
-              Self::view(window, cx, #struct_name_ident::default())
+              Self::view(window, cx, #struct_name_path::default())
           }
       }
 
       impl #struct_name_form_ident {
-          pub fn view(window: &mut Window, cx: &mut App, original_data: #struct_name_ident) -> Entity<Self> {
+          pub fn view(window: &mut Window, cx: &mut App, original_data: #struct_name_ident #generic_args) -> Entity<Self> {
               cx.new(|cx| Self::new(window, cx, original_data))
           }
 
           #event_handlers_tokens
 
-          fn new(window: &mut Window, cx: &mut Context<Self>, original_data: #struct_name_ident) -> Self {
+          fn submit(&mut self, cx: &mut Context<Self>) {
+              #submit_check
+              self.original_data = Arc::new(self.current_data.clone().into());
+              cx.notify();
+          }
+
+          fn new(window: &mut Window, cx: &mut Context<Self>, original_data: #struct_name_ident #generic_args) -> Self {
+            let current_data: #struct_name_uw_ident #generic_args = original_data.clone().into();
+
             #component_creations_tokens
 
             #subscription_calls_tokens
 
+            let mut fields = #struct_name_form_fields_ident {
+                #field_initializers_tokens
+            };
+            fields.load(&current_data, window, cx);
+
               Self {
-                  original_data: Arc::new(original_data.clone()),
-                  current_data: original_data.into(),
-                  fields: #struct_name_form_fields_ident {
-                    #field_initializers_tokens
-                  },
+                  original_data: Arc::new(original_data),
+                  current_data,
+                  fields,
                   focus_handle: cx.focus_handle(),
                   #subscriptions_init
               }
@@ -152,6 +223,8 @@ fn layout(data: &GpuiFormShape) -> syn::File {
 
       impl Render for #struct_name_form_ident {
           fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+              #validation_tokens
+
               v_flex()
                   .key_context(CONTEXT)
                   .id(#form_id_literal)
@@ -164,6 +237,13 @@ fn layout(data: &GpuiFormShape) -> syn::File {
                       v_form()
                         #render_children_tokens
                   )
+                  .child(
+                      Button::new("submit")
+                          .primary()
+                          .label("Save")
+                          .disabled(#submit_disabled)
+                          .on_click(cx.listener(|this, _, _, cx| this.submit(cx))),
+                  )
                   .child(Divider::horizontal())
                   .absolute()
                   .child(format!("{:?}", self.current_data))